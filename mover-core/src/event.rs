@@ -0,0 +1,124 @@
+//! A typed model of the input events `ActionRecorder` captures.
+//!
+//! Each [`Event`] carries a monotonic `timestamp` measured as a [`Duration`]
+//! since recording started, so a recording can be replayed at the original
+//! pace (or faster/slower) by sleeping the gap between consecutive events.
+
+use std::time::Duration;
+
+use crate::{MouseButton, Modifiers, Point, ScrollDirection};
+
+/// The set of mouse buttons held down at the moment a [`Event::MouseMove`]
+/// was captured.
+///
+/// Unlike [`Modifiers`], button state isn't a small fixed set of bits -
+/// [`MouseButton::Navigate`] carries a payload - so this is a thin ordered-set
+/// wrapper rather than a bitflag struct.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ButtonSet(Vec<MouseButton>);
+
+impl ButtonSet {
+    /// Creates an empty button set.
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Returns `true` if `button` is held.
+    pub fn contains(&self, button: MouseButton) -> bool {
+        self.0.contains(&button)
+    }
+
+    /// Marks `button` as held, if it isn't already.
+    pub fn insert(&mut self, button: MouseButton) {
+        if !self.contains(button) {
+            self.0.push(button);
+        }
+    }
+
+    /// Marks `button` as released.
+    pub fn remove(&mut self, button: MouseButton) {
+        self.0.retain(|b| *b != button);
+    }
+
+    /// Returns `true` if no buttons are held.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the currently held buttons.
+    pub fn iter(&self) -> impl Iterator<Item = &MouseButton> {
+        self.0.iter()
+    }
+}
+
+impl From<Vec<MouseButton>> for ButtonSet {
+    fn from(buttons: Vec<MouseButton>) -> Self {
+        Self(buttons)
+    }
+}
+
+/// What happened to a key during a recorded [`Event::Key`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KeyAction {
+    /// A key was pressed and held.
+    Press(String),
+    /// A previously pressed key was released.
+    Release(String),
+    /// A string was typed as a single burst (e.g. via `type_string`).
+    Type(String),
+}
+
+/// A single recorded input event, timestamped relative to the start of the
+/// recording.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Event {
+    /// The pointer moved to `pos` while `buttons` were held.
+    MouseMove {
+        pos: Point,
+        buttons: ButtonSet,
+        timestamp: Duration,
+    },
+    /// `button` was pressed at `pos` while `modifiers` were held.
+    MouseDown {
+        button: MouseButton,
+        pos: Point,
+        modifiers: Modifiers,
+        timestamp: Duration,
+    },
+    /// `button` was released at `pos` while `modifiers` were held.
+    MouseUp {
+        button: MouseButton,
+        pos: Point,
+        modifiers: Modifiers,
+        timestamp: Duration,
+    },
+    /// The wheel moved by `amount` clicks in `direction` at `pos`.
+    Scroll {
+        direction: ScrollDirection,
+        amount: i32,
+        pos: Point,
+        timestamp: Duration,
+    },
+    /// A keyboard action occurred.
+    Key {
+        action: KeyAction,
+        timestamp: Duration,
+    },
+}
+
+impl Event {
+    /// The moment this event was captured, relative to the start of the
+    /// recording.
+    pub fn timestamp(&self) -> Duration {
+        match self {
+            Event::MouseMove { timestamp, .. }
+            | Event::MouseDown { timestamp, .. }
+            | Event::MouseUp { timestamp, .. }
+            | Event::Scroll { timestamp, .. }
+            | Event::Key { timestamp, .. } => *timestamp,
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! A typed keyboard key vocabulary.
+//!
+//! The rest of the crate's automation surface historically took key names as
+//! bare `&str`s, which only fails at the point a backend tries (and fails) to
+//! look the name up. `Key` gives callers compile-time checking and exhaustive
+//! matching instead, while [`FromStr`] keeps every existing string-based call
+//! site working unchanged.
+
+use std::str::FromStr;
+
+use crate::MoverError;
+
+/// A single keyboard key, modeled on a full scancode table rather than the
+/// handful of keys a given script happens to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Key {
+    A, B, C, D, E, F, G, H, I, J, K, L, M,
+    N, O, P, Q, R, S, T, U, V, W, X, Y, Z,
+    Key0, Key1, Key2, Key3, Key4, Key5, Key6, Key7, Key8, Key9,
+    F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12,
+    F13, F14, F15, F16, F17, F18, F19, F20, F21, F22, F23, F24,
+    Numpad0, Numpad1, Numpad2, Numpad3, Numpad4,
+    Numpad5, Numpad6, Numpad7, Numpad8, Numpad9,
+    NumpadAdd, NumpadSubtract, NumpadMultiply, NumpadDivide, NumpadDecimal, NumpadEnter,
+    Insert, Home, End, PageUp, PageDown,
+    Snapshot, Scroll, Pause,
+    Up, Down, Left, Right,
+    Return, Escape, Tab, BackTab, Space, Backspace, Delete,
+    Control, Shift, Alt, Meta,
+    /// A literal unicode character not covered by a named variant above.
+    Char(char),
+}
+
+/// A media / consumer-control button - volume, playback, and similar system
+/// keys that live outside the typing/modifier keys [`Key`] models.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MediaButton {
+    VolumeUp,
+    VolumeDown,
+    Mute,
+    MediaPlayPause,
+    MediaNext,
+    MediaPrev,
+    MediaStop,
+}
+
+impl FromStr for Key {
+    type Err = MoverError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.to_lowercase();
+        let key = match lower.as_str() {
+            "a" => Key::A, "b" => Key::B, "c" => Key::C, "d" => Key::D, "e" => Key::E,
+            "f" => Key::F, "g" => Key::G, "h" => Key::H, "i" => Key::I, "j" => Key::J,
+            "k" => Key::K, "l" => Key::L, "m" => Key::M, "n" => Key::N, "o" => Key::O,
+            "p" => Key::P, "q" => Key::Q, "r" => Key::R, "s" => Key::S, "t" => Key::T,
+            "u" => Key::U, "v" => Key::V, "w" => Key::W, "x" => Key::X, "y" => Key::Y,
+            "z" => Key::Z,
+
+            "0" => Key::Key0, "1" => Key::Key1, "2" => Key::Key2, "3" => Key::Key3,
+            "4" => Key::Key4, "5" => Key::Key5, "6" => Key::Key6, "7" => Key::Key7,
+            "8" => Key::Key8, "9" => Key::Key9,
+
+            "f1" => Key::F1, "f2" => Key::F2, "f3" => Key::F3, "f4" => Key::F4,
+            "f5" => Key::F5, "f6" => Key::F6, "f7" => Key::F7, "f8" => Key::F8,
+            "f9" => Key::F9, "f10" => Key::F10, "f11" => Key::F11, "f12" => Key::F12,
+            "f13" => Key::F13, "f14" => Key::F14, "f15" => Key::F15, "f16" => Key::F16,
+            "f17" => Key::F17, "f18" => Key::F18, "f19" => Key::F19, "f20" => Key::F20,
+            "f21" => Key::F21, "f22" => Key::F22, "f23" => Key::F23, "f24" => Key::F24,
+
+            "numpad0" | "num0" => Key::Numpad0, "numpad1" | "num1" => Key::Numpad1,
+            "numpad2" | "num2" => Key::Numpad2, "numpad3" | "num3" => Key::Numpad3,
+            "numpad4" | "num4" => Key::Numpad4, "numpad5" | "num5" => Key::Numpad5,
+            "numpad6" | "num6" => Key::Numpad6, "numpad7" | "num7" => Key::Numpad7,
+            "numpad8" | "num8" => Key::Numpad8, "numpad9" | "num9" => Key::Numpad9,
+            "numpadadd" | "add" | "numadd" => Key::NumpadAdd,
+            "numpadsubtract" | "subtract" | "numsub" => Key::NumpadSubtract,
+            "numpadmultiply" | "multiply" | "nummul" => Key::NumpadMultiply,
+            "numpaddivide" | "divide" | "numdiv" => Key::NumpadDivide,
+            "numpaddecimal" | "decimal" | "numdecimal" => Key::NumpadDecimal,
+            "numpadenter" | "numenter" => Key::NumpadEnter,
+
+            "insert" | "ins" => Key::Insert,
+            "home" => Key::Home,
+            "end" => Key::End,
+            "pageup" | "pgup" => Key::PageUp,
+            "pagedown" | "pgdn" => Key::PageDown,
+
+            "snapshot" | "printscreen" | "prtsc" => Key::Snapshot,
+            "scroll" | "scrolllock" => Key::Scroll,
+            "pause" | "break" => Key::Pause,
+
+            "up" => Key::Up,
+            "down" => Key::Down,
+            "left" => Key::Left,
+            "right" => Key::Right,
+
+            "enter" | "return" => Key::Return,
+            "escape" | "esc" => Key::Escape,
+            "tab" => Key::Tab,
+            "backtab" => Key::BackTab,
+            "space" => Key::Space,
+            "backspace" => Key::Backspace,
+            "delete" | "del" => Key::Delete,
+
+            "ctrl" | "control" => Key::Control,
+            "alt" => Key::Alt,
+            "shift" => Key::Shift,
+            "meta" | "win" | "command" => Key::Meta,
+
+            _ => {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Key::Char(c),
+                    _ => return Err(MoverError::Other(format!("unsupported key: {}", s))),
+                }
+            }
+        };
+        Ok(key)
+    }
+}
+
+impl FromStr for MediaButton {
+    type Err = MoverError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let button = match s.to_lowercase().as_str() {
+            "volumeup" => MediaButton::VolumeUp,
+            "volumedown" => MediaButton::VolumeDown,
+            "mute" => MediaButton::Mute,
+            "playpause" | "mediaplaypause" => MediaButton::MediaPlayPause,
+            "next" | "medianext" | "medianexttrack" => MediaButton::MediaNext,
+            "prev" | "mediaprev" | "mediaprevtrack" => MediaButton::MediaPrev,
+            "stop" | "mediastop" => MediaButton::MediaStop,
+            _ => return Err(MoverError::Other(format!("unsupported media button: {}", s))),
+        };
+        Ok(button)
+    }
+}
@@ -1,16 +1,28 @@
 //! Core types and traits for the mover automation library
 
+pub mod dpi;
 pub mod error;
+pub mod event;
+pub mod key;
+pub mod listener;
 pub mod platform;
 pub mod types;
 
+pub use dpi::*;
 pub use error::*;
+pub use event::*;
+pub use key::*;
+pub use listener::*;
 pub use platform::*;
 pub use types::*;
 
 /// Re-export common types
 pub mod prelude {
+    pub use crate::dpi::*;
     pub use crate::error::*;
+    pub use crate::event::*;
+    pub use crate::key::*;
+    pub use crate::listener::*;
     pub use crate::platform::*;
     pub use crate::types::*;
 }
@@ -0,0 +1,242 @@
+//! Interpreting the raw [`crate::Event`] stream [`crate::CapturePlatform`]
+//! delivers into the higher-level gestures GUI input layers expose (clicks,
+//! double/triple clicks, drags).
+//!
+//! This builds on [`crate::CapturePlatform`] rather than wiring a second,
+//! independent OS hook: every platform already has exactly one way to
+//! observe system-wide input (a `CGEventTap` on macOS, `WH_MOUSE_LL` on
+//! Windows, the X11 RECORD extension on Linux), and [`GestureDecoder`] turns
+//! that single raw stream into [`MouseEvent`]s in pure, platform-independent
+//! code.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::{ButtonSet, Modifiers, MouseButton, Point, ScrollDelta, ScrollDirection};
+
+/// A high-level mouse gesture, synthesized from the raw press/release/move
+/// stream [`crate::CapturePlatform`] delivers.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MouseEvent {
+    /// `button` was pressed at `pos` while `modifiers` were held.
+    MouseDown {
+        pos: Point,
+        button: MouseButton,
+        held: ButtonSet,
+        modifiers: Modifiers,
+        timestamp: Duration,
+    },
+    /// `button` was released at `pos` while `modifiers` were held.
+    MouseUp {
+        pos: Point,
+        button: MouseButton,
+        held: ButtonSet,
+        modifiers: Modifiers,
+        timestamp: Duration,
+    },
+    /// The pointer moved to `pos` while `held` buttons were down.
+    MouseMoved {
+        pos: Point,
+        held: ButtonSet,
+        modifiers: Modifiers,
+        timestamp: Duration,
+    },
+    /// The wheel moved at `pos`.
+    ScrollWheel {
+        pos: Point,
+        delta: ScrollDelta,
+        modifiers: Modifiers,
+        timestamp: Duration,
+    },
+    /// A second `MouseUp` for the same button landed within
+    /// [`GestureDecoder::CLICK_TIMEOUT`] and [`GestureDecoder::CLICK_POS_TOLERANCE`]
+    /// of the previous one, with no drag in between.
+    DoubleClick {
+        pos: Point,
+        button: MouseButton,
+        modifiers: Modifiers,
+        timestamp: Duration,
+    },
+    /// A third `MouseUp` for the same button landed within the click window.
+    TripleClick {
+        pos: Point,
+        button: MouseButton,
+        modifiers: Modifiers,
+        timestamp: Duration,
+    },
+    /// `button` was pressed at `start`, the pointer moved past
+    /// [`GestureDecoder::DRAG_THRESHOLD`] pixels before release, and was
+    /// released at `pos`.
+    Drag {
+        start: Point,
+        pos: Point,
+        button: MouseButton,
+        modifiers: Modifiers,
+        timestamp: Duration,
+    },
+}
+
+/// Tracks an in-progress press so `feed` can tell a plain click from a drag.
+struct PressState {
+    start: Point,
+    dragging: bool,
+}
+
+/// The last completed click for a button, used to detect the next one
+/// landing close enough in time and space to chain into a double/triple
+/// click.
+struct ClickRecord {
+    pos: Point,
+    timestamp: Duration,
+    count: u32,
+}
+
+/// Turns a raw [`crate::Event`] stream into semantic [`MouseEvent`]s.
+///
+/// Stateful and single-threaded: feed it every event from one
+/// [`crate::CapturePlatform::start_capture`] callback, in order.
+pub struct GestureDecoder {
+    held: ButtonSet,
+    modifiers: Modifiers,
+    presses: HashMap<MouseButton, PressState>,
+    last_clicks: HashMap<MouseButton, ClickRecord>,
+}
+
+impl GestureDecoder {
+    /// Two releases of the same button within this long of each other may
+    /// chain into a double/triple click.
+    pub const CLICK_TIMEOUT: Duration = Duration::from_millis(500);
+    /// Two releases of the same button within this many pixels of each other
+    /// may chain into a double/triple click.
+    pub const CLICK_POS_TOLERANCE: i32 = 5;
+    /// How far the pointer must move between a press and its release before
+    /// it counts as a drag rather than a click.
+    pub const DRAG_THRESHOLD: i32 = 4;
+
+    /// Creates a decoder with no history - the first event fed to it is
+    /// treated as happening in a fresh session.
+    pub fn new() -> Self {
+        Self {
+            held: ButtonSet::new(),
+            modifiers: Modifiers::NONE,
+            presses: HashMap::new(),
+            last_clicks: HashMap::new(),
+        }
+    }
+
+    /// Feeds one raw event into the decoder, returning the semantic events it
+    /// synthesizes (zero or more, e.g. a drag-ending `MouseUp` also yields a
+    /// `Drag`, and a fast `MouseUp` also yields a `DoubleClick`/`TripleClick`).
+    pub fn feed(&mut self, event: &crate::Event) -> Vec<MouseEvent> {
+        match event {
+            crate::Event::MouseMove { pos, buttons, timestamp } => {
+                self.held = buttons.clone();
+                for press in self.presses.values_mut() {
+                    if !press.dragging && pos.distance_to(&press.start) as i32 >= Self::DRAG_THRESHOLD {
+                        press.dragging = true;
+                    }
+                }
+                vec![MouseEvent::MouseMoved {
+                    pos: *pos,
+                    held: buttons.clone(),
+                    modifiers: self.modifiers,
+                    timestamp: *timestamp,
+                }]
+            }
+            crate::Event::MouseDown { button, pos, modifiers, timestamp } => {
+                self.modifiers = *modifiers;
+                self.held.insert(*button);
+                self.presses.insert(*button, PressState { start: *pos, dragging: false });
+                vec![MouseEvent::MouseDown {
+                    pos: *pos,
+                    button: *button,
+                    held: self.held.clone(),
+                    modifiers: *modifiers,
+                    timestamp: *timestamp,
+                }]
+            }
+            crate::Event::MouseUp { button, pos, modifiers, timestamp } => {
+                self.modifiers = *modifiers;
+                self.held.remove(*button);
+                let mut out = vec![MouseEvent::MouseUp {
+                    pos: *pos,
+                    button: *button,
+                    held: self.held.clone(),
+                    modifiers: *modifiers,
+                    timestamp: *timestamp,
+                }];
+
+                let press = self.presses.remove(button);
+                let dragging = press.as_ref().map(|p| p.dragging).unwrap_or(false);
+                if dragging {
+                    let start = press.map(|p| p.start).unwrap_or(*pos);
+                    self.last_clicks.remove(button);
+                    out.push(MouseEvent::Drag {
+                        start,
+                        pos: *pos,
+                        button: *button,
+                        modifiers: *modifiers,
+                        timestamp: *timestamp,
+                    });
+                } else {
+                    let count = match self.last_clicks.get(button) {
+                        Some(last)
+                            if timestamp.saturating_sub(last.timestamp) <= Self::CLICK_TIMEOUT
+                                && pos.distance_to(&last.pos) as i32 <= Self::CLICK_POS_TOLERANCE =>
+                        {
+                            last.count + 1
+                        }
+                        _ => 1,
+                    };
+                    self.last_clicks.insert(*button, ClickRecord { pos: *pos, timestamp: *timestamp, count });
+
+                    match count {
+                        2 => out.push(MouseEvent::DoubleClick {
+                            pos: *pos, button: *button, modifiers: *modifiers, timestamp: *timestamp,
+                        }),
+                        n if n >= 3 => out.push(MouseEvent::TripleClick {
+                            pos: *pos, button: *button, modifiers: *modifiers, timestamp: *timestamp,
+                        }),
+                        _ => {}
+                    }
+                }
+
+                out
+            }
+            crate::Event::Scroll { direction, amount, pos, timestamp } => {
+                let value = (*amount as f32) * direction.value() as f32;
+                let delta = match direction {
+                    ScrollDirection::Up | ScrollDirection::Down => ScrollDelta::Lines { x: 0.0, y: value },
+                    ScrollDirection::Left | ScrollDirection::Right => ScrollDelta::Lines { x: value, y: 0.0 },
+                };
+                vec![MouseEvent::ScrollWheel { pos: *pos, delta, modifiers: self.modifiers, timestamp: *timestamp }]
+            }
+            crate::Event::Key { .. } => Vec::new(),
+        }
+    }
+}
+
+impl Default for GestureDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Platform-specific global mouse *gesture* listening, built on top of
+/// [`crate::CapturePlatform`]'s raw event stream.
+pub trait InputListener {
+    /// Creates a listener, failing the same way the underlying
+    /// [`crate::Platform`] would (e.g. missing Accessibility permission on
+    /// macOS) rather than letting that surface later as a silent no-op.
+    fn new_listener() -> crate::MoverResult<Self>
+    where
+        Self: Sized;
+
+    /// Starts delivering synthesized [`MouseEvent`]s to `callback` until
+    /// [`InputListener::stop`] is called. Blocks the calling thread for as
+    /// long as the capture runs, same as [`crate::CapturePlatform::start_capture`].
+    fn start(&self, callback: Box<dyn FnMut(MouseEvent) + Send>) -> crate::MoverResult<()>;
+
+    /// Stops a listener started with [`InputListener::start`].
+    fn stop(&self) -> crate::MoverResult<()>;
+}
@@ -1,6 +1,6 @@
 //! Platform abstraction layer for the mover library
 
-use crate::{MoverResult, Point, Size, MouseButton, TweenFn};
+use crate::{CaptureFormat, Display, Modifiers, MoverResult, Point, Size, MouseButton, MouseCursor, ScrollDelta, TweenFn};
 
 /// Platform-specific mouse operations
 pub trait MousePlatform {
@@ -40,11 +40,45 @@ pub trait MousePlatform {
     /// Drag mouse relative to current position
     fn drag_by(&self, dx: i32, dy: i32, button: MouseButton) -> MoverResult<()>;
     
-    /// Scroll vertically
-    fn scroll_vertical(&self, clicks: i32) -> MoverResult<()>;
-    
-    /// Scroll horizontally
-    fn scroll_horizontal(&self, clicks: i32) -> MoverResult<()>;
+    /// Scroll by a precise delta, in lines or pixels.
+    ///
+    /// Platforms that can't emit pixel-precision wheel events should convert
+    /// via [`ScrollDelta::to_lines`] rather than rejecting the call.
+    fn scroll(&self, delta: ScrollDelta) -> MoverResult<()>;
+
+    /// Scroll vertically by a number of wheel clicks. A thin wrapper over
+    /// [`MousePlatform::scroll`] building `ScrollDelta::Lines { x: 0.0, y: clicks }`.
+    fn scroll_vertical(&self, clicks: i32) -> MoverResult<()> {
+        self.scroll(ScrollDelta::Lines { x: 0.0, y: clicks as f32 })
+    }
+
+    /// Scroll horizontally by a number of wheel clicks. A thin wrapper over
+    /// [`MousePlatform::scroll`] building `ScrollDelta::Lines { x: clicks, y: 0.0 }`.
+    fn scroll_horizontal(&self, clicks: i32) -> MoverResult<()> {
+        self.scroll(ScrollDelta::Lines { x: clicks as f32, y: 0.0 })
+    }
+
+    /// Set the visible cursor shape. Platforms that can't honor a given
+    /// shape should return a `MoverError::PlatformNotSupported`-style error
+    /// rather than silently leaving the previous cursor in place.
+    fn set_cursor(&self, cursor: MouseCursor) -> MoverResult<()>;
+
+    /// Get the currently visible cursor shape.
+    fn get_cursor(&self) -> MoverResult<MouseCursor>;
+
+    /// Reports whether the OS has swapped the primary/secondary mouse
+    /// buttons for left-handed use (`SPI_GETMOUSEBUTTONSWAP` on Windows, the
+    /// pointer button mapping on Linux, the "Swipe left/right" equivalent
+    /// `NSEvent` button-swap setting on macOS).
+    ///
+    /// Used to resolve [`MouseButton::Primary`]/[`MouseButton::Secondary`] to
+    /// the physical button the user actually expects.
+    fn is_button_swapped(&self) -> MoverResult<bool>;
+
+    /// Reads the live held/released state of `button` directly from the OS
+    /// (`CGEventSourceButtonState` on macOS), independent of whether this
+    /// process posted the press itself.
+    fn is_button_pressed(&self, button: MouseButton) -> MoverResult<bool>;
 }
 
 /// Platform-specific screen operations
@@ -55,14 +89,25 @@ pub trait ScreenPlatform {
     /// Check if coordinates are on screen
     fn is_on_screen(&self, x: i32, y: i32) -> MoverResult<bool>;
     
-    /// Take a screenshot
-    fn capture_screen(&self) -> MoverResult<Vec<u8>>;
-    
-    /// Capture a region of the screen
-    fn capture_region(&self, x: i32, y: i32, width: u32, height: u32) -> MoverResult<Vec<u8>>;
+    /// Take a screenshot, returning the raw buffer alongside its pixel layout
+    /// so callers can decode it without assuming a format.
+    fn capture_screen(&self) -> MoverResult<(Vec<u8>, CaptureFormat)>;
+
+    /// Capture a region of the screen, returning the raw buffer alongside its
+    /// pixel layout so callers can decode it without assuming a format.
+    fn capture_region(&self, x: i32, y: i32, width: u32, height: u32) -> MoverResult<(Vec<u8>, CaptureFormat)>;
     
     /// Get pixel color at coordinates
     fn get_pixel_color(&self, x: i32, y: i32) -> MoverResult<(u8, u8, u8)>;
+
+    /// Get the primary display's scale factor (1.0 on a standard-DPI display,
+    /// 2.0 on a 2x Retina/HiDPI display, etc.), used to convert between
+    /// logical and physical coordinates.
+    fn scale_factor(&self) -> MoverResult<f64>;
+
+    /// Enumerate every physical display attached to the system, in global
+    /// virtual-desktop coordinates.
+    fn displays(&self) -> MoverResult<Vec<Display>>;
 }
 
 /// Platform-specific keyboard operations
@@ -82,15 +127,51 @@ pub trait KeyboardPlatform {
     /// Press multiple keys in sequence
     fn press_keys(&self, keys: &[&str]) -> MoverResult<()>;
     
-    /// Press hotkey combination
+    /// Press hotkey combination. Implementations should separate modifier
+    /// tokens ("cmd"/"ctrl"/"alt"/"shift") from the remaining keys, press the
+    /// modifiers down first, tap the rest, then release the modifiers in
+    /// reverse order - and guarantee that release happens even if a press or
+    /// tap errors partway through, to avoid leaving a modifier stuck down.
     fn press_hotkey(&self, keys: &[&str]) -> MoverResult<()>;
+
+    /// Reads the live state of the modifier keys directly from the OS
+    /// (`CGEventSourceFlagsState` on macOS), rather than tracking it from
+    /// presses this process made - this also reflects modifiers held from
+    /// outside the process.
+    fn get_modifiers(&self) -> MoverResult<Modifiers>;
+
+    /// Reads the live held/released state of `key` directly from the OS
+    /// (`CGEventSourceKeyState` on macOS), independent of whether this
+    /// process posted the press itself. `key` uses the same vocabulary as
+    /// [`KeyboardPlatform::press_key`].
+    fn is_key_pressed(&self, key: &str) -> MoverResult<bool>;
+}
+
+/// Platform-specific global input capture - listening to the OS input
+/// stream rather than synthesizing it.
+pub trait CapturePlatform {
+    /// Starts forwarding every mouse/keyboard input event system-wide to
+    /// `sink`, with each [`crate::Event`]'s timestamp measured from the
+    /// moment this call begins.
+    ///
+    /// This call is expected to block the calling thread for as long as the
+    /// capture runs (mirroring e.g. the X11 RECORD extension's
+    /// `XRecordEnableContext`, which blocks its connection for the
+    /// duration) - callers that want capture to run alongside other work
+    /// should run it on a dedicated thread and use [`CapturePlatform::stop_capture`]
+    /// to end it.
+    fn start_capture(&self, sink: Box<dyn FnMut(crate::Event) + Send>) -> MoverResult<()>;
+
+    /// Stops a capture started with [`CapturePlatform::start_capture`], if
+    /// one is running, unblocking its thread.
+    fn stop_capture(&self) -> MoverResult<()>;
 }
 
 /// Platform trait that combines all platform operations
-pub trait Platform: MousePlatform + ScreenPlatform + KeyboardPlatform {
+pub trait Platform: MousePlatform + ScreenPlatform + KeyboardPlatform + CapturePlatform {
     /// Get the platform name
     fn name(&self) -> &'static str;
-    
+
     /// Check if the platform supports a specific feature
     fn supports_feature(&self, feature: &str) -> bool;
 }
@@ -1,21 +1,78 @@
 //! Linux platform implementation for mover
-//! 
-//! This module will contain the actual Linux X11/Wayland implementation.
-//! Currently a placeholder for future development.
+//!
+//! **Scope of this file:** this is display-server *session detection* only,
+//! not the X11/XCB/XTEST backend - `MousePlatform`, `ScreenPlatform`, and
+//! `KeyboardPlatform` below are `unimplemented!()` in every method except
+//! `is_button_swapped`, which returns an `UnsupportedOperation` error.
+//! A real backend needs an X11/XCB client (for `warp_pointer`/`query_pointer`/
+//! `get_image` and XTEST synthetic input events) and, ideally, a Wayland
+//! fallback for the subset of operations portals allow. Neither `xcb` nor
+//! `x11rb` is a dependency of this workspace yet - there is no `Cargo.toml`
+//! anywhere in this tree to add one to - so that backend is out of reach
+//! here and remains future work rather than something guessed at with an
+//! unverifiable FFI surface.
+//!
+//! What *is* implemented here without a new dependency is session
+//! detection, so [`Platform::supports_feature`] can give a truthful answer
+//! about which *display server* is in use, which is the first thing a real
+//! XCB/Wayland-portal backend would need to branch on.
 
-use crate::{MoverResult, Point, Size, MouseButton, TweenFn};
+use crate::{CaptureFormat, Display, MoverResult, Point, Size, MouseButton, MouseCursor, ScrollDelta, TweenFn};
 
-/// Linux platform implementation
-pub struct LinuxPlatform;
+/// The Linux display server protocol in use, detected from the environment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinuxSessionType {
+    /// An X11 (or XWayland) display is available.
+    X11,
+    /// A native Wayland compositor session, with no X11 display available.
+    Wayland,
+    /// Neither `DISPLAY` nor `WAYLAND_DISPLAY` is set (e.g. a headless TTY).
+    Unknown,
+}
+
+impl LinuxSessionType {
+    /// Detects the session type from `XDG_SESSION_TYPE`, `WAYLAND_DISPLAY`,
+    /// and `DISPLAY`, preferring an explicit `XDG_SESSION_TYPE` when set.
+    fn detect() -> Self {
+        match std::env::var("XDG_SESSION_TYPE").as_deref() {
+            Ok("wayland") => return LinuxSessionType::Wayland,
+            Ok("x11") => return LinuxSessionType::X11,
+            _ => {}
+        }
+
+        if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+            LinuxSessionType::Wayland
+        } else if std::env::var_os("DISPLAY").is_some() {
+            LinuxSessionType::X11
+        } else {
+            LinuxSessionType::Unknown
+        }
+    }
+}
+
+/// Linux platform implementation.
+pub struct LinuxPlatform {
+    session: LinuxSessionType,
+}
 
 impl LinuxPlatform {
-    /// Create a new Linux platform instance
+    /// Create a new Linux platform instance, detecting the active display
+    /// server session so `supports_feature` can answer meaningfully.
     pub fn new() -> MoverResult<Self> {
-        Ok(Self)
+        Ok(Self {
+            session: LinuxSessionType::detect(),
+        })
+    }
+
+    /// The detected display server session.
+    pub fn session_type(&self) -> LinuxSessionType {
+        self.session
     }
 }
 
-// TODO: Implement actual Linux platform functionality
+// TODO: Implement actual Linux platform functionality via XCB/XTEST (X11)
+// and the relevant portals (Wayland), once this workspace has a manifest to
+// depend on `xcb`/`x11rb`/`ashpd`.
 impl super::MousePlatform for LinuxPlatform {
     fn get_position(&self) -> MoverResult<Point> {
         unimplemented!("Linux mouse position not yet implemented")
@@ -65,12 +122,34 @@ impl super::MousePlatform for LinuxPlatform {
         unimplemented!("Linux mouse drag by not yet implemented")
     }
     
-    fn scroll_vertical(&self, _clicks: i32) -> MoverResult<()> {
-        unimplemented!("Linux vertical scroll not yet implemented")
+    fn scroll(&self, _delta: ScrollDelta) -> MoverResult<()> {
+        unimplemented!("Linux scroll not yet implemented")
     }
-    
-    fn scroll_horizontal(&self, _clicks: i32) -> MoverResult<()> {
-        unimplemented!("Linux horizontal scroll not yet implemented")
+
+    fn set_cursor(&self, _cursor: MouseCursor) -> MoverResult<()> {
+        unimplemented!("Linux cursor shape not yet implemented")
+    }
+
+    fn get_cursor(&self) -> MoverResult<MouseCursor> {
+        unimplemented!("Linux cursor shape not yet implemented")
+    }
+
+    fn is_button_pressed(&self, _button: MouseButton) -> MoverResult<bool> {
+        unimplemented!("Linux mouse button state query not yet implemented")
+    }
+
+    fn is_button_swapped(&self) -> MoverResult<bool> {
+        // Lives in the X11 input-device properties ("Evdev Axes Swap" /
+        // libinput's left-handed setting) or the Wayland compositor's own
+        // config, neither of which is reachable without an XCB/portal
+        // dependency - report it as unsupported rather than guessing, so
+        // callers that only need a safe default (see `mover_mouse::convert_button`)
+        // can fall back instead of panicking on valid input.
+        Err(crate::MoverError::PlatformError(
+            crate::PlatformError::UnsupportedOperation(
+                "Linux mouse button swap setting not yet implemented".to_string(),
+            ),
+        ))
     }
 }
 
@@ -83,17 +162,25 @@ impl super::ScreenPlatform for LinuxPlatform {
         unimplemented!("Linux on screen check not yet implemented")
     }
     
-    fn capture_screen(&self) -> MoverResult<Vec<u8>> {
+    fn capture_screen(&self) -> MoverResult<(Vec<u8>, CaptureFormat)> {
         unimplemented!("Linux screen capture not yet implemented")
     }
-    
-    fn capture_region(&self, _x: i32, _y: i32, _width: u32, _height: u32) -> MoverResult<Vec<u8>> {
+
+    fn capture_region(&self, _x: i32, _y: i32, _width: u32, _height: u32) -> MoverResult<(Vec<u8>, CaptureFormat)> {
         unimplemented!("Linux region capture not yet implemented")
     }
     
     fn get_pixel_color(&self, _x: i32, _y: i32) -> MoverResult<(u8, u8, u8)> {
         unimplemented!("Linux pixel color not yet implemented")
     }
+
+    fn scale_factor(&self) -> MoverResult<f64> {
+        unimplemented!("Linux scale factor not yet implemented")
+    }
+
+    fn displays(&self) -> MoverResult<Vec<Display>> {
+        unimplemented!("Linux display enumeration not yet implemented")
+    }
 }
 
 impl super::KeyboardPlatform for LinuxPlatform {
@@ -120,6 +207,54 @@ impl super::KeyboardPlatform for LinuxPlatform {
     fn press_hotkey(&self, _keys: &[&str]) -> MoverResult<()> {
         unimplemented!("Linux keyboard hotkey not yet implemented")
     }
+
+    fn get_modifiers(&self) -> MoverResult<crate::Modifiers> {
+        unimplemented!("Linux modifier state query not yet implemented")
+    }
+
+    fn is_key_pressed(&self, _key: &str) -> MoverResult<bool> {
+        unimplemented!("Linux key state query not yet implemented")
+    }
+}
+
+// A real implementation needs the X11 RECORD extension, which requires two
+// separate connections to the X server:
+// - a "control" connection used to register the recording context
+//   (`XRecordCreateContext` with an `XRecordAllocRange` enabling
+//   `KeyPress`/`KeyRelease`/`ButtonPress`/`ButtonRelease`/`MotionNotify` on
+//   `XRecordAllClients`) and then call `XRecordEnableContext`, which blocks
+//   that connection for as long as the capture runs, invoking a callback for
+//   every matching event;
+// - a separate "data" connection left free for any other Xlib/XCB calls
+//   (e.g. the `MousePlatform`/`ScreenPlatform` queries above), since the
+//   control connection is unusable for anything else while blocked.
+// Each delivered `xRecordEnableContextReply` carries raw `xEvent` bytes that
+// need decoding into button/keycode and root-window coordinate fields before
+// they can be turned into a [`crate::Event`] with a timestamp relative to
+// when `start_capture` began. `stop_capture` then calls
+// `XRecordDisableContext` (over the data connection) to unblock the control
+// connection's `XRecordEnableContext` call. None of this is available
+// without an `xcb`/`x11rb` dependency, so it is reported as unsupported
+// rather than attempted here. Returning an error (instead of panicking via
+// `unimplemented!()`) lets callers like `mover_utils::ActionRecorder` that
+// run this on a background thread surface the failure cleanly instead of
+// the thread dying with an unhandled panic.
+impl super::CapturePlatform for LinuxPlatform {
+    fn start_capture(&self, _sink: Box<dyn FnMut(crate::Event) + Send>) -> MoverResult<()> {
+        Err(crate::MoverError::PlatformError(
+            crate::PlatformError::UnsupportedOperation(
+                "Linux global input capture (X11 RECORD extension) not yet implemented".to_string(),
+            ),
+        ))
+    }
+
+    fn stop_capture(&self) -> MoverResult<()> {
+        Err(crate::MoverError::PlatformError(
+            crate::PlatformError::UnsupportedOperation(
+                "Linux global input capture not yet implemented".to_string(),
+            ),
+        ))
+    }
 }
 
 impl super::Platform for LinuxPlatform {
@@ -127,7 +262,16 @@ impl super::Platform for LinuxPlatform {
         "Linux"
     }
     
-    fn supports_feature(&self, _feature: &str) -> bool {
-        false // No features supported yet
+    fn supports_feature(&self, feature: &str) -> bool {
+        // None of the automation primitives above are implemented yet on
+        // either session type, so every concrete feature is still `false`.
+        // The session-probe queries are the one thing we can answer
+        // truthfully today without an XCB/portal dependency.
+        match feature {
+            "session_x11" => self.session == LinuxSessionType::X11,
+            "session_wayland" => self.session == LinuxSessionType::Wayland,
+            "capture" => false,
+            _ => false,
+        }
     }
 }
@@ -1,124 +1,843 @@
-//! macOS platform implementation for mover
-//! 
-//! This module will contain the actual macOS Core Graphics implementation.
-//! Currently a placeholder for future development.
+//! macOS platform implementation for mover, backed by Core Graphics / Quartz
+//! Event Services.
+//!
+//! Every synthetic event below is built with `CGEventCreate*` and posted via
+//! `CGEventPost(kCGHIDEventTap, ...)`, which is the same injection point
+//! System Events and Accessibility tools use. Posting *any* synthetic event
+//! this way requires the process to hold Accessibility permission, so
+//! [`MacOSPlatform::new`] probes `AXIsProcessTrusted()` up front and returns
+//! a clear [`PlatformError::MacOS`] rather than letting every subsequent
+//! call fail silently.
+//!
+//! A few operations have no Core Graphics equivalent at all - custom cursor
+//! shapes and the left/right button swap setting are AppKit (`NSCursor`/
+//! `NSEvent`) concepts, not Quartz ones - and return an `UnsupportedOperation`
+//! error saying so rather than being faked.
 
-use crate::{MoverResult, Point, Size, MouseButton, TweenFn};
+use std::ffi::c_void;
 
-/// macOS platform implementation
+use crate::{CaptureFormat, Display, DisplayId, Modifiers, MoverError, MoverResult, NavigationDirection, PixelFormat, PlatformError, Point, Region, Size, MouseButton, MouseCursor, ScrollDelta, TweenFn};
+
+mod ffi {
+    use super::*;
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct CGPoint {
+        pub x: f64,
+        pub y: f64,
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct CGSize {
+        pub width: f64,
+        pub height: f64,
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct CGRect {
+        pub origin: CGPoint,
+        pub size: CGSize,
+    }
+
+    pub type CGDirectDisplayID = u32;
+    pub type CGError = i32;
+    pub type CGEventRef = *mut c_void;
+    pub type CGEventSourceRef = *mut c_void;
+    pub type CGImageRef = *mut c_void;
+    pub type CGDataProviderRef = *mut c_void;
+    pub type CFDataRef = *mut c_void;
+    pub type CGEventTapLocation = u32;
+    pub type CGEventType = u32;
+    pub type CGMouseButton = u32;
+    pub type CGKeyCode = u16;
+    pub type CGEventField = u32;
+    pub type CGScrollEventUnit = u32;
+    pub type CGEventSourceStateID = i32;
+    pub type CGEventFlags = u64;
+
+    pub const K_CG_HID_EVENT_TAP: CGEventTapLocation = 0;
+
+    pub const K_CG_EVENT_LEFT_MOUSE_DOWN: CGEventType = 1;
+    pub const K_CG_EVENT_LEFT_MOUSE_UP: CGEventType = 2;
+    pub const K_CG_EVENT_RIGHT_MOUSE_DOWN: CGEventType = 3;
+    pub const K_CG_EVENT_RIGHT_MOUSE_UP: CGEventType = 4;
+    pub const K_CG_EVENT_MOUSE_MOVED: CGEventType = 5;
+    pub const K_CG_EVENT_LEFT_MOUSE_DRAGGED: CGEventType = 6;
+    pub const K_CG_EVENT_RIGHT_MOUSE_DRAGGED: CGEventType = 7;
+    pub const K_CG_EVENT_OTHER_MOUSE_DOWN: CGEventType = 25;
+    pub const K_CG_EVENT_OTHER_MOUSE_UP: CGEventType = 26;
+    pub const K_CG_EVENT_OTHER_MOUSE_DRAGGED: CGEventType = 27;
+
+    pub const K_CG_MOUSE_BUTTON_LEFT: CGMouseButton = 0;
+    pub const K_CG_MOUSE_BUTTON_RIGHT: CGMouseButton = 1;
+    pub const K_CG_MOUSE_BUTTON_CENTER: CGMouseButton = 2;
+
+    pub const K_CG_SCROLL_EVENT_UNIT_LINE: CGScrollEventUnit = 1;
+
+    /// `kCGMouseEventClickState`: how many clicks this event is part of
+    /// (1 = single, 2 = double, 3 = triple).
+    pub const K_CG_MOUSE_EVENT_CLICK_STATE: CGEventField = 1;
+
+    /// `kCGEventSourceStateHIDSystemState`: the combined state of all
+    /// physically connected input devices, independent of this process.
+    pub const K_CG_EVENT_SOURCE_STATE_HID_SYSTEM_STATE: CGEventSourceStateID = 1;
+
+    pub const K_CG_EVENT_FLAG_MASK_SHIFT: CGEventFlags = 0x00020000;
+    pub const K_CG_EVENT_FLAG_MASK_CONTROL: CGEventFlags = 0x00040000;
+    pub const K_CG_EVENT_FLAG_MASK_ALTERNATE: CGEventFlags = 0x00080000;
+    pub const K_CG_EVENT_FLAG_MASK_COMMAND: CGEventFlags = 0x00100000;
+
+    #[link(name = "ApplicationServices", kind = "framework")]
+    extern "C" {
+        pub fn CGEventCreate(source: CGEventSourceRef) -> CGEventRef;
+        pub fn CGEventCreateMouseEvent(
+            source: CGEventSourceRef,
+            mouse_type: CGEventType,
+            mouse_cursor_position: CGPoint,
+            mouse_button: CGMouseButton,
+        ) -> CGEventRef;
+        pub fn CGEventCreateKeyboardEvent(
+            source: CGEventSourceRef,
+            virtual_key: CGKeyCode,
+            key_down: bool,
+        ) -> CGEventRef;
+        pub fn CGEventCreateScrollWheelEvent(
+            source: CGEventSourceRef,
+            units: CGScrollEventUnit,
+            wheel_count: u32,
+            wheel1: i32,
+            ...
+        ) -> CGEventRef;
+        pub fn CGEventPost(tap: CGEventTapLocation, event: CGEventRef);
+        pub fn CGEventSetIntegerValueField(event: CGEventRef, field: CGEventField, value: i64);
+        pub fn CGEventKeyboardSetUnicodeString(
+            event: CGEventRef,
+            length: usize,
+            unicode_string: *const u16,
+        );
+        pub fn CGEventGetLocation(event: CGEventRef) -> CGPoint;
+        pub fn CGEventSourceFlagsState(state_id: CGEventSourceStateID) -> CGEventFlags;
+        pub fn CGEventSetFlags(event: CGEventRef, flags: CGEventFlags);
+        pub fn CGEventSourceKeyState(state_id: CGEventSourceStateID, key: CGKeyCode) -> bool;
+        pub fn CGEventSourceButtonState(state_id: CGEventSourceStateID, button: CGMouseButton) -> bool;
+
+        pub fn CGMainDisplayID() -> CGDirectDisplayID;
+        pub fn CGDisplayBounds(display: CGDirectDisplayID) -> CGRect;
+        pub fn CGDisplayIsMain(display: CGDirectDisplayID) -> u32;
+        pub fn CGDisplayPixelsWide(display: CGDirectDisplayID) -> usize;
+        pub fn CGDisplayPixelsHigh(display: CGDirectDisplayID) -> usize;
+        pub fn CGGetActiveDisplayList(
+            max_displays: u32,
+            active_displays: *mut CGDirectDisplayID,
+            display_count: *mut u32,
+        ) -> CGError;
+        pub fn CGDisplayCreateImage(display: CGDirectDisplayID) -> CGImageRef;
+        pub fn CGDisplayCreateImageForRect(display: CGDirectDisplayID, rect: CGRect) -> CGImageRef;
+
+        pub fn CGImageGetWidth(image: CGImageRef) -> usize;
+        pub fn CGImageGetHeight(image: CGImageRef) -> usize;
+        pub fn CGImageGetBytesPerRow(image: CGImageRef) -> usize;
+        pub fn CGImageGetDataProvider(image: CGImageRef) -> CGDataProviderRef;
+        pub fn CGDataProviderCopyData(provider: CGDataProviderRef) -> CFDataRef;
+
+        pub fn CFDataGetBytePtr(data: CFDataRef) -> *const u8;
+        pub fn CFDataGetLength(data: CFDataRef) -> isize;
+        pub fn CFRelease(cf: *const c_void);
+
+        pub fn AXIsProcessTrusted() -> bool;
+    }
+
+    // CFRelease takes a non-null, non-mut pointer; every *Ref above is really
+    // a CF/CG object and gets released the same way.
+    pub unsafe fn release(obj: *mut c_void) {
+        if !obj.is_null() {
+            CFRelease(obj as *const c_void);
+        }
+    }
+}
+
+use ffi::*;
+
+/// Maps a key name (the same vocabulary `mover_keyboard::Keyboard` accepts)
+/// to its macOS virtual keycode, plus whether it needs Shift held to
+/// produce the requested character (e.g. an uppercase letter or a shifted
+/// symbol like `!`).
+fn key_to_keycode(key: &str) -> MoverResult<(CGKeyCode, bool)> {
+    let lower = key.to_lowercase();
+    if let Some(c) = single_char(&lower, key) {
+        return char_to_keycode(c);
+    }
+
+    let code = match lower.as_str() {
+        "enter" | "return" => 0x24,
+        "tab" => 0x30,
+        "space" => 0x31,
+        "backspace" | "delete" => 0x33,
+        "del" => 0x75, // forward delete
+        "escape" | "esc" => 0x35,
+        "ctrl" | "control" => 0x3B,
+        "alt" => 0x3A,
+        "shift" => 0x38,
+        "meta" | "win" | "command" | "cmd" => 0x37,
+        "capslock" => 0x39,
+        "up" => 0x7E,
+        "down" => 0x7D,
+        "left" => 0x7B,
+        "right" => 0x7C,
+        "home" => 0x73,
+        "end" => 0x77,
+        "pageup" | "pgup" => 0x74,
+        "pagedown" | "pgdn" => 0x79,
+        "f1" => 0x7A, "f2" => 0x78, "f3" => 0x63, "f4" => 0x76, "f5" => 0x60,
+        "f6" => 0x61, "f7" => 0x62, "f8" => 0x64, "f9" => 0x65, "f10" => 0x6D,
+        "f11" => 0x67, "f12" => 0x6F, "f13" => 0x69, "f14" => 0x6B, "f15" => 0x71,
+        "f16" => 0x6A, "f17" => 0x40, "f18" => 0x4F, "f19" => 0x50, "f20" => 0x5A,
+        _ => return Err(MoverError::PlatformError(PlatformError::MacOS(
+            format!("unsupported key: {}", key)
+        ))),
+    };
+    Ok((code, false))
+}
+
+/// Extracts a single character from `lower`/`original` if the key name is
+/// exactly one character (case preserved from `original` so e.g. `"A"`
+/// still maps to a shifted `a`).
+fn single_char(lower: &str, original: &str) -> Option<char> {
+    let mut chars = original.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => {
+            let _ = lower;
+            None
+        }
+    }
+}
+
+/// Maps a single character to its virtual keycode on a US-QWERTY layout,
+/// plus whether Shift must be held to produce it.
+fn char_to_keycode(c: char) -> MoverResult<(CGKeyCode, bool)> {
+    let lower = c.to_ascii_lowercase();
+    let needs_shift = c.is_ascii_uppercase() || "!@#$%^&*()_+{}|:\"<>?~".contains(c);
+    let base = if c.is_ascii_alphabetic() { lower } else { c };
+
+    let code = match base {
+        'a' => 0x00, 'b' => 0x0B, 'c' => 0x08, 'd' => 0x02, 'e' => 0x0E,
+        'f' => 0x03, 'g' => 0x05, 'h' => 0x04, 'i' => 0x22, 'j' => 0x26,
+        'k' => 0x28, 'l' => 0x25, 'm' => 0x2E, 'n' => 0x2D, 'o' => 0x1F,
+        'p' => 0x23, 'q' => 0x0C, 'r' => 0x0F, 's' => 0x01, 't' => 0x11,
+        'u' => 0x20, 'v' => 0x09, 'w' => 0x0D, 'x' => 0x07, 'y' => 0x10,
+        'z' => 0x06,
+        '1' | '!' => 0x12, '2' | '@' => 0x13, '3' | '#' => 0x14, '4' | '$' => 0x15,
+        '5' | '%' => 0x17, '6' | '^' => 0x16, '7' | '&' => 0x1A, '8' | '*' => 0x1C,
+        '9' | '(' => 0x19, '0' | ')' => 0x1D,
+        '-' | '_' => 0x1B, '=' | '+' => 0x18,
+        '[' | '{' => 0x21, ']' | '}' => 0x1E, '\\' | '|' => 0x2A,
+        ';' | ':' => 0x29, '\'' | '"' => 0x27,
+        ',' | '<' => 0x2B, '.' | '>' => 0x2F, '/' | '?' => 0x2C,
+        '`' | '~' => 0x32,
+        ' ' => 0x31,
+        _ => return Err(MoverError::PlatformError(PlatformError::MacOS(
+            format!("unsupported character: {:?}", c)
+        ))),
+    };
+    Ok((code, needs_shift))
+}
+
+/// Whether `key` names a modifier key rather than a regular one, used by
+/// `press_hotkey` to press modifiers first and release them last.
+fn is_modifier_token(key: &str) -> bool {
+    matches!(
+        key.to_lowercase().as_str(),
+        "cmd" | "command" | "meta" | "win" | "ctrl" | "control" | "alt" | "shift"
+    )
+}
+
+/// Releases every key pushed onto it, in reverse press order, when dropped -
+/// guarantees modifiers used by `press_hotkey` don't get stuck down even if
+/// a later press/release in the chord errors out partway through.
+struct ReleaseGuard<'a> {
+    platform: &'a MacOSPlatform,
+    pressed: Vec<String>,
+}
+
+impl<'a> Drop for ReleaseGuard<'a> {
+    fn drop(&mut self) {
+        for key in self.pressed.iter().rev() {
+            let _ = super::KeyboardPlatform::release_key(self.platform, key);
+        }
+    }
+}
+
+fn cg_error(context: &str, detail: impl std::fmt::Display) -> MoverError {
+    MoverError::PlatformError(PlatformError::MacOS(format!("{}: {}", context, detail)))
+}
+
+fn button_to_cg(button: MouseButton) -> (CGEventType, CGEventType, CGEventType, CGMouseButton) {
+    // `kCGMouseEventButtonNumber` is 0-indexed (left=0, right=1, center=2,
+    // then the extra side buttons from 3 up), which is a different scheme
+    // from `MouseButton::number()`'s 1-indexed X11 convention - this table
+    // is specific to what Core Graphics expects.
+    match button {
+        MouseButton::Left | MouseButton::Primary => (
+            K_CG_EVENT_LEFT_MOUSE_DOWN,
+            K_CG_EVENT_LEFT_MOUSE_UP,
+            K_CG_EVENT_LEFT_MOUSE_DRAGGED,
+            K_CG_MOUSE_BUTTON_LEFT,
+        ),
+        MouseButton::Right | MouseButton::Secondary => (
+            K_CG_EVENT_RIGHT_MOUSE_DOWN,
+            K_CG_EVENT_RIGHT_MOUSE_UP,
+            K_CG_EVENT_RIGHT_MOUSE_DRAGGED,
+            K_CG_MOUSE_BUTTON_RIGHT,
+        ),
+        MouseButton::Middle => (
+            K_CG_EVENT_OTHER_MOUSE_DOWN,
+            K_CG_EVENT_OTHER_MOUSE_UP,
+            K_CG_EVENT_OTHER_MOUSE_DRAGGED,
+            K_CG_MOUSE_BUTTON_CENTER,
+        ),
+        // The browser/file-manager "navigate back/forward" side buttons -
+        // X1 and X2 in the convention other terminal/window crates use.
+        MouseButton::Navigate(NavigationDirection::Back) => (
+            K_CG_EVENT_OTHER_MOUSE_DOWN,
+            K_CG_EVENT_OTHER_MOUSE_UP,
+            K_CG_EVENT_OTHER_MOUSE_DRAGGED,
+            3,
+        ),
+        MouseButton::Navigate(NavigationDirection::Forward) => (
+            K_CG_EVENT_OTHER_MOUSE_DOWN,
+            K_CG_EVENT_OTHER_MOUSE_UP,
+            K_CG_EVENT_OTHER_MOUSE_DRAGGED,
+            4,
+        ),
+        MouseButton::Button4 => (K_CG_EVENT_OTHER_MOUSE_DOWN, K_CG_EVENT_OTHER_MOUSE_UP, K_CG_EVENT_OTHER_MOUSE_DRAGGED, 5),
+        MouseButton::Button5 => (K_CG_EVENT_OTHER_MOUSE_DOWN, K_CG_EVENT_OTHER_MOUSE_UP, K_CG_EVENT_OTHER_MOUSE_DRAGGED, 6),
+        MouseButton::Button6 => (K_CG_EVENT_OTHER_MOUSE_DOWN, K_CG_EVENT_OTHER_MOUSE_UP, K_CG_EVENT_OTHER_MOUSE_DRAGGED, 7),
+        MouseButton::Button7 => (K_CG_EVENT_OTHER_MOUSE_DOWN, K_CG_EVENT_OTHER_MOUSE_UP, K_CG_EVENT_OTHER_MOUSE_DRAGGED, 8),
+    }
+}
+
+/// macOS platform implementation, backed by Core Graphics / Quartz Event
+/// Services.
 pub struct MacOSPlatform;
 
 impl MacOSPlatform {
-    /// Create a new macOS platform instance
+    /// Creates a new macOS platform instance, failing up front if the
+    /// process lacks Accessibility permission - every method below posts
+    /// synthetic events via `CGEventPost`, which silently does nothing
+    /// without it, so surfacing the failure here is far more useful than
+    /// having every call quietly no-op.
     pub fn new() -> MoverResult<Self> {
+        if !unsafe { AXIsProcessTrusted() } {
+            return Err(MoverError::PlatformError(PlatformError::MacOS(
+                "process is not trusted for Accessibility access - grant it in \
+                 System Settings > Privacy & Security > Accessibility".to_string()
+            )));
+        }
         Ok(Self)
     }
+
+    fn post_mouse_event(&self, event_type: CGEventType, pos: Point, button: CGMouseButton) -> MoverResult<()> {
+        unsafe {
+            let event = CGEventCreateMouseEvent(
+                std::ptr::null_mut(),
+                event_type,
+                CGPoint { x: pos.x as f64, y: pos.y as f64 },
+                button,
+            );
+            if event.is_null() {
+                return Err(cg_error("failed to create mouse event", "null CGEventRef"));
+            }
+            CGEventPost(K_CG_HID_EVENT_TAP, event);
+            release(event);
+        }
+        Ok(())
+    }
+
+    fn click_with_count(&self, button: MouseButton, pos: Point, click_count: i64) -> MoverResult<()> {
+        let (down, up, _, cg_button) = button_to_cg(button);
+        unsafe {
+            let down_event = CGEventCreateMouseEvent(
+                std::ptr::null_mut(), down, CGPoint { x: pos.x as f64, y: pos.y as f64 }, cg_button,
+            );
+            let up_event = CGEventCreateMouseEvent(
+                std::ptr::null_mut(), up, CGPoint { x: pos.x as f64, y: pos.y as f64 }, cg_button,
+            );
+            if down_event.is_null() || up_event.is_null() {
+                release(down_event);
+                release(up_event);
+                return Err(cg_error("failed to create click event", "null CGEventRef"));
+            }
+            CGEventSetIntegerValueField(down_event, K_CG_MOUSE_EVENT_CLICK_STATE, click_count);
+            CGEventSetIntegerValueField(up_event, K_CG_MOUSE_EVENT_CLICK_STATE, click_count);
+            CGEventPost(K_CG_HID_EVENT_TAP, down_event);
+            CGEventPost(K_CG_HID_EVENT_TAP, up_event);
+            release(down_event);
+            release(up_event);
+        }
+        Ok(())
+    }
+
+    fn post_key_event(&self, keycode: CGKeyCode, key_down: bool, needs_shift: bool) -> MoverResult<()> {
+        unsafe {
+            let mut shift_event = std::ptr::null_mut();
+            if needs_shift && key_down {
+                let (shift_code, _) = key_to_keycode("shift")?;
+                shift_event = CGEventCreateKeyboardEvent(std::ptr::null_mut(), shift_code, true);
+                if !shift_event.is_null() {
+                    CGEventPost(K_CG_HID_EVENT_TAP, shift_event);
+                }
+            }
+
+            let event = CGEventCreateKeyboardEvent(std::ptr::null_mut(), keycode, key_down);
+            if event.is_null() {
+                release(shift_event);
+                return Err(cg_error("failed to create keyboard event", "null CGEventRef"));
+            }
+            // Stamp the event with whatever modifiers are currently held (by
+            // this chord or from outside the process) so apps that read
+            // `CGEventFlags` off the key event itself, rather than tracking
+            // separate modifier key events, still see them.
+            let mut flags = CGEventSourceFlagsState(K_CG_EVENT_SOURCE_STATE_HID_SYSTEM_STATE);
+            if needs_shift {
+                flags |= K_CG_EVENT_FLAG_MASK_SHIFT;
+            }
+            CGEventSetFlags(event, flags);
+            CGEventPost(K_CG_HID_EVENT_TAP, event);
+            release(event);
+
+            if needs_shift && !key_down {
+                let (shift_code, _) = key_to_keycode("shift")?;
+                let shift_up = CGEventCreateKeyboardEvent(std::ptr::null_mut(), shift_code, false);
+                if !shift_up.is_null() {
+                    CGEventPost(K_CG_HID_EVENT_TAP, shift_up);
+                    release(shift_up);
+                }
+            } else {
+                release(shift_event);
+            }
+        }
+        Ok(())
+    }
+
+    fn displays_raw(&self) -> MoverResult<Vec<CGDirectDisplayID>> {
+        const MAX_DISPLAYS: u32 = 32;
+        let mut ids = [0u32; MAX_DISPLAYS as usize];
+        let mut count = 0u32;
+        let err = unsafe { CGGetActiveDisplayList(MAX_DISPLAYS, ids.as_mut_ptr(), &mut count) };
+        if err != 0 {
+            return Err(cg_error("CGGetActiveDisplayList failed", err));
+        }
+        Ok(ids[..count as usize].to_vec())
+    }
+
+    /// The backing scale factor of `display` (1.0 on a standard-DPI display,
+    /// 2.0 on Retina), derived without AppKit by comparing the display's
+    /// physical pixel width (`CGDisplayPixelsWide`, which already reflects
+    /// the current backing resolution) to its width in the logical "points"
+    /// coordinate space mouse coordinates and `CGDisplayBounds` use
+    /// (`NSScreen.backingScaleFactor`'s Core Graphics equivalent).
+    fn display_scale_factor(&self, display: CGDirectDisplayID) -> MoverResult<f64> {
+        let logical_width = unsafe { CGDisplayBounds(display) }.size.width;
+        if logical_width <= 0.0 {
+            return Err(cg_error("scale factor query failed", "zero-width display bounds"));
+        }
+        let physical_width = unsafe { CGDisplayPixelsWide(display) } as f64;
+        Ok(physical_width / logical_width)
+    }
+
+    fn capture_image(&self, image: CGImageRef) -> MoverResult<(Vec<u8>, CaptureFormat)> {
+        unsafe {
+            if image.is_null() {
+                return Err(cg_error("screen capture failed", "null CGImageRef"));
+            }
+            let width = CGImageGetWidth(image) as u32;
+            let height = CGImageGetHeight(image) as u32;
+            let stride = CGImageGetBytesPerRow(image) as u32;
+
+            let provider = CGImageGetDataProvider(image);
+            let data = CGDataProviderCopyData(provider);
+            if data.is_null() {
+                release(image);
+                return Err(cg_error("screen capture failed", "null CFDataRef"));
+            }
+
+            let ptr = CFDataGetBytePtr(data);
+            let len = CFDataGetLength(data) as usize;
+            let bytes = std::slice::from_raw_parts(ptr, len).to_vec();
+
+            release(data);
+            release(image);
+
+            Ok((bytes, CaptureFormat {
+                pixel_format: PixelFormat::Bgra8,
+                width,
+                height,
+                stride,
+                top_down: true,
+            }))
+        }
+    }
 }
 
-// TODO: Implement actual macOS platform functionality
 impl super::MousePlatform for MacOSPlatform {
     fn get_position(&self) -> MoverResult<Point> {
-        unimplemented!("macOS mouse position not yet implemented")
+        unsafe {
+            let event = CGEventCreate(std::ptr::null_mut());
+            if event.is_null() {
+                return Err(cg_error("failed to query mouse position", "null CGEventRef"));
+            }
+            let location = CGEventGetLocation(event);
+            release(event);
+            Ok(Point::new(location.x.round() as i32, location.y.round() as i32))
+        }
     }
-    
-    fn move_to(&self, _x: i32, _y: i32) -> MoverResult<()> {
-        unimplemented!("macOS mouse move not yet implemented")
+
+    fn move_to(&self, x: i32, y: i32) -> MoverResult<()> {
+        self.post_mouse_event(K_CG_EVENT_MOUSE_MOVED, Point::new(x, y), K_CG_MOUSE_BUTTON_LEFT)
     }
-    
-    fn move_by(&self, _dx: i32, _dy: i32) -> MoverResult<()> {
-        unimplemented!("macOS mouse move by not yet implemented")
+
+    fn move_by(&self, dx: i32, dy: i32) -> MoverResult<()> {
+        let pos = self.get_position()?;
+        self.move_to(pos.x + dx, pos.y + dy)
+    }
+
+    fn move_to_with_tween(&self, x: i32, y: i32, duration: f64, tween: TweenFn) -> MoverResult<()> {
+        if duration <= 0.0 {
+            return self.move_to(x, y);
+        }
+
+        let start = self.get_position()?;
+        let steps = (duration * 60.0).max(1.0) as usize;
+        for i in 0..=steps {
+            let progress = tween(i as f64 / steps as f64);
+            let cur_x = start.x + ((x - start.x) as f64 * progress) as i32;
+            let cur_y = start.y + ((y - start.y) as f64 * progress) as i32;
+            self.move_to(cur_x, cur_y)?;
+            if i < steps {
+                std::thread::sleep(std::time::Duration::from_secs_f64(duration / steps as f64));
+            }
+        }
+        Ok(())
+    }
+
+    fn click(&self, button: MouseButton) -> MoverResult<()> {
+        let pos = self.get_position()?;
+        self.click_with_count(button, pos, 1)
+    }
+
+    fn click_at(&self, x: i32, y: i32, button: MouseButton) -> MoverResult<()> {
+        self.move_to(x, y)?;
+        self.click_with_count(button, Point::new(x, y), 1)
     }
-    
-    fn move_to_with_tween(&self, _x: i32, _y: i32, _duration: f64, _tween: TweenFn) -> MoverResult<()> {
-        unimplemented!("macOS mouse move with tween not yet implemented")
+
+    fn double_click(&self, button: MouseButton) -> MoverResult<()> {
+        let pos = self.get_position()?;
+        self.click_with_count(button, pos, 2)
     }
-    
-    fn click(&self, _button: MouseButton) -> MoverResult<()> {
-        unimplemented!("macOS mouse click not yet implemented")
+
+    fn triple_click(&self, button: MouseButton) -> MoverResult<()> {
+        let pos = self.get_position()?;
+        self.click_with_count(button, pos, 3)
     }
-    
-    fn click_at(&self, _x: i32, _y: i32, _button: MouseButton) -> MoverResult<()> {
-        unimplemented!("macOS mouse click at not yet implemented")
+
+    fn press_button(&self, button: MouseButton) -> MoverResult<()> {
+        let pos = self.get_position()?;
+        let (down, _, _, cg_button) = button_to_cg(button);
+        self.post_mouse_event(down, pos, cg_button)
     }
-    
-    fn double_click(&self, _button: MouseButton) -> MoverResult<()> {
-        unimplemented!("macOS double click not yet implemented")
+
+    fn release_button(&self, button: MouseButton) -> MoverResult<()> {
+        let pos = self.get_position()?;
+        let (_, up, _, cg_button) = button_to_cg(button);
+        self.post_mouse_event(up, pos, cg_button)
     }
-    
-    fn triple_click(&self, _button: MouseButton) -> MoverResult<()> {
-        unimplemented!("macOS triple click not yet implemented")
+
+    fn drag_to(&self, x: i32, y: i32, button: MouseButton) -> MoverResult<()> {
+        let (_, _, dragged, cg_button) = button_to_cg(button);
+        self.post_mouse_event(dragged, Point::new(x, y), cg_button)
     }
-    
-    fn press_button(&self, _button: MouseButton) -> MoverResult<()> {
-        unimplemented!("macOS mouse button press not yet implemented")
+
+    fn drag_by(&self, dx: i32, dy: i32, button: MouseButton) -> MoverResult<()> {
+        let pos = self.get_position()?;
+        self.drag_to(pos.x + dx, pos.y + dy, button)
     }
-    
-    fn release_button(&self, _button: MouseButton) -> MoverResult<()> {
-        unimplemented!("macOS mouse button release not yet implemented")
+
+    fn scroll(&self, delta: ScrollDelta) -> MoverResult<()> {
+        let (x, y) = delta.to_lines();
+        unsafe {
+            let event = CGEventCreateScrollWheelEvent(
+                std::ptr::null_mut(),
+                K_CG_SCROLL_EVENT_UNIT_LINE,
+                2,
+                y.round() as i32,
+                x.round() as i32,
+            );
+            if event.is_null() {
+                return Err(cg_error("failed to create scroll event", "null CGEventRef"));
+            }
+            CGEventPost(K_CG_HID_EVENT_TAP, event);
+            release(event);
+        }
+        Ok(())
     }
-    
-    fn drag_to(&self, _x: i32, _y: i32, _button: MouseButton) -> MoverResult<()> {
-        unimplemented!("macOS mouse drag to not yet implemented")
+
+    fn set_cursor(&self, _cursor: MouseCursor) -> MoverResult<()> {
+        // Custom cursor shapes are an AppKit `NSCursor` concept, not a Core
+        // Graphics one - there is no `CGSetCursor`-style call to make here.
+        Err(crate::MoverError::PlatformError(
+            crate::PlatformError::UnsupportedOperation(
+                "macOS cursor shape requires AppKit (NSCursor), not yet implemented".to_string(),
+            ),
+        ))
     }
-    
-    fn drag_by(&self, _dx: i32, _dy: i32, _button: MouseButton) -> MoverResult<()> {
-        unimplemented!("macOS mouse drag by not yet implemented")
+
+    fn get_cursor(&self) -> MoverResult<MouseCursor> {
+        Err(crate::MoverError::PlatformError(
+            crate::PlatformError::UnsupportedOperation(
+                "macOS cursor shape requires AppKit (NSCursor), not yet implemented".to_string(),
+            ),
+        ))
     }
-    
-    fn scroll_vertical(&self, _clicks: i32) -> MoverResult<()> {
-        unimplemented!("macOS vertical scroll not yet implemented")
+
+    fn is_button_pressed(&self, button: MouseButton) -> MoverResult<bool> {
+        let (.., cg_button) = button_to_cg(button);
+        Ok(unsafe { CGEventSourceButtonState(K_CG_EVENT_SOURCE_STATE_HID_SYSTEM_STATE, cg_button) })
     }
-    
-    fn scroll_horizontal(&self, _clicks: i32) -> MoverResult<()> {
-        unimplemented!("macOS horizontal scroll not yet implemented")
+
+    fn is_button_swapped(&self) -> MoverResult<bool> {
+        // The primary/secondary button swap lives in `NSEvent`/IOKit HID
+        // parameters, not Core Graphics - report it as unsupported rather
+        // than guessing, so callers that only need a safe default (see
+        // `mover_mouse::convert_button`) can fall back instead of panicking
+        // on valid input.
+        Err(crate::MoverError::PlatformError(
+            crate::PlatformError::UnsupportedOperation(
+                "macOS button swap setting requires AppKit/IOKit, not yet implemented".to_string(),
+            ),
+        ))
     }
 }
 
+/// Every coordinate `MousePlatform` takes or returns (`move_to`, `get_position`,
+/// `capture_region`'s `x`/`y`/`width`/`height`) is in logical "points", the
+/// same space `CGDisplayBounds` reports and what `NSEvent`/`NSScreen` call
+/// "points" - *not* physical backing pixels. Buffers returned by
+/// `capture_screen`/`capture_region` are the exception: their `CaptureFormat`
+/// reports the actual physical pixel dimensions Core Graphics filled in
+/// (`width * scale_factor()` by `height * scale_factor()` on a Retina
+/// display), since a screenshot is only useful at the resolution it was
+/// captured at.
 impl super::ScreenPlatform for MacOSPlatform {
     fn get_size(&self) -> MoverResult<Size> {
-        unimplemented!("macOS screen size not yet implemented")
+        let bounds = unsafe { CGDisplayBounds(CGMainDisplayID()) };
+        Ok(Size::new(bounds.size.width.round() as i32, bounds.size.height.round() as i32))
+    }
+
+    fn is_on_screen(&self, x: i32, y: i32) -> MoverResult<bool> {
+        let size = self.get_size()?;
+        Ok(x >= 0 && x < size.width && y >= 0 && y < size.height)
+    }
+
+    fn capture_screen(&self) -> MoverResult<(Vec<u8>, CaptureFormat)> {
+        let image = unsafe { CGDisplayCreateImage(CGMainDisplayID()) };
+        self.capture_image(image)
     }
-    
-    fn is_on_screen(&self, _x: i32, _y: i32) -> MoverResult<bool> {
-        unimplemented!("macOS on screen check not yet implemented")
+
+    /// `x`/`y`/`width`/`height` are logical points; `CGDisplayCreateImageForRect`
+    /// resolves them against the display's backing resolution itself, so the
+    /// returned buffer is `width * scale_factor()` by `height * scale_factor()`
+    /// physical pixels - exactly 2x the requested size on a Retina display,
+    /// reported accurately via the returned `CaptureFormat`, not assumed.
+    fn capture_region(&self, x: i32, y: i32, width: u32, height: u32) -> MoverResult<(Vec<u8>, CaptureFormat)> {
+        let rect = CGRect {
+            origin: CGPoint { x: x as f64, y: y as f64 },
+            size: CGSize { width: width as f64, height: height as f64 },
+        };
+        let image = unsafe { CGDisplayCreateImageForRect(CGMainDisplayID(), rect) };
+        self.capture_image(image)
     }
-    
-    fn capture_screen(&self) -> MoverResult<Vec<u8>> {
-        unimplemented!("macOS screen capture not yet implemented")
+
+    /// `x`/`y` are a logical point; capturing a 1x1-point region yields the
+    /// `scale_factor() x scale_factor()` block of physical pixels it covers,
+    /// and the top-left one (byte offset 0, regardless of that block's size)
+    /// is the correct physical sample for the requested logical coordinate.
+    fn get_pixel_color(&self, x: i32, y: i32) -> MoverResult<(u8, u8, u8)> {
+        let (bytes, format) = self.capture_region(x, y, 1, 1)?;
+        if bytes.len() < 4 {
+            return Err(cg_error("pixel capture returned too few bytes", bytes.len()));
+        }
+        // Bgra8, top-down, single pixel at byte offset 0.
+        debug_assert_eq!(format.pixel_format, PixelFormat::Bgra8);
+        let (b, g, r) = (bytes[0], bytes[1], bytes[2]);
+        Ok((r, g, b))
     }
-    
-    fn capture_region(&self, _x: i32, _y: i32, _width: u32, _height: u32) -> MoverResult<Vec<u8>> {
-        unimplemented!("macOS region capture not yet implemented")
+
+    fn scale_factor(&self) -> MoverResult<f64> {
+        self.display_scale_factor(unsafe { CGMainDisplayID() })
     }
-    
-    fn get_pixel_color(&self, _x: i32, _y: i32) -> MoverResult<(u8, u8, u8)> {
-        unimplemented!("macOS pixel color not yet implemented")
+
+    fn displays(&self) -> MoverResult<Vec<Display>> {
+        let ids = self.displays_raw()?;
+        let mut displays = Vec::with_capacity(ids.len());
+        for id in ids {
+            let bounds = unsafe { CGDisplayBounds(id) };
+            let is_primary = unsafe { CGDisplayIsMain(id) } != 0;
+            let scale_factor = self.display_scale_factor(id).unwrap_or(1.0);
+            displays.push(Display {
+                id: DisplayId(id),
+                bounds: Region::new(
+                    bounds.origin.x.round() as i32,
+                    bounds.origin.y.round() as i32,
+                    bounds.size.width.round() as u32,
+                    bounds.size.height.round() as u32,
+                ),
+                scale_factor,
+                is_primary,
+            });
+        }
+        Ok(displays)
     }
 }
 
 impl super::KeyboardPlatform for MacOSPlatform {
-    fn type_string(&self, _text: &str) -> MoverResult<()> {
-        unimplemented!("macOS keyboard type string not yet implemented")
+    fn type_string(&self, text: &str) -> MoverResult<()> {
+        unsafe {
+            let utf16: Vec<u16> = text.encode_utf16().collect();
+
+            let down = CGEventCreateKeyboardEvent(std::ptr::null_mut(), 0, true);
+            if down.is_null() {
+                return Err(cg_error("failed to create type event", "null CGEventRef"));
+            }
+            CGEventKeyboardSetUnicodeString(down, utf16.len(), utf16.as_ptr());
+            CGEventPost(K_CG_HID_EVENT_TAP, down);
+            release(down);
+
+            // A key-down with no matching key-up leaves the synthetic key
+            // logically held, which makes commit/repeat behavior unreliable
+            // in some apps - post the same unicode string on the up event too.
+            let up = CGEventCreateKeyboardEvent(std::ptr::null_mut(), 0, false);
+            if up.is_null() {
+                return Err(cg_error("failed to create type event", "null CGEventRef"));
+            }
+            CGEventKeyboardSetUnicodeString(up, utf16.len(), utf16.as_ptr());
+            CGEventPost(K_CG_HID_EVENT_TAP, up);
+            release(up);
+        }
+        Ok(())
+    }
+
+    fn press_key(&self, key: &str) -> MoverResult<()> {
+        let (code, needs_shift) = key_to_keycode(key)?;
+        self.post_key_event(code, true, needs_shift)
+    }
+
+    fn release_key(&self, key: &str) -> MoverResult<()> {
+        let (code, needs_shift) = key_to_keycode(key)?;
+        self.post_key_event(code, false, needs_shift)
+    }
+
+    fn hold_key(&self, key: &str) -> MoverResult<()> {
+        self.press_key(key)
+    }
+
+    fn press_keys(&self, keys: &[&str]) -> MoverResult<()> {
+        for key in keys {
+            self.press_key(key)?;
+            self.release_key(key)?;
+        }
+        Ok(())
+    }
+
+    fn press_hotkey(&self, keys: &[&str]) -> MoverResult<()> {
+        let mut guard = ReleaseGuard { platform: self, pressed: Vec::new() };
+
+        for key in keys.iter().filter(|k| is_modifier_token(k)) {
+            self.press_key(key)?;
+            guard.pressed.push((*key).to_string());
+        }
+        for key in keys.iter().filter(|k| !is_modifier_token(k)) {
+            self.press_key(key)?;
+            self.release_key(key)?;
+        }
+
+        // `guard` drops here (and on every early `?` return above), releasing
+        // the held modifiers in reverse order.
+        Ok(())
     }
-    
-    fn press_key(&self, _key: &str) -> MoverResult<()> {
-        unimplemented!("macOS keyboard press key not yet implemented")
+
+    fn get_modifiers(&self) -> MoverResult<Modifiers> {
+        let flags = unsafe { CGEventSourceFlagsState(K_CG_EVENT_SOURCE_STATE_HID_SYSTEM_STATE) };
+        let mut modifiers = Modifiers::NONE;
+        if flags & K_CG_EVENT_FLAG_MASK_SHIFT != 0 {
+            modifiers = modifiers | Modifiers::SHIFT;
+        }
+        if flags & K_CG_EVENT_FLAG_MASK_CONTROL != 0 {
+            modifiers = modifiers | Modifiers::CTRL;
+        }
+        if flags & K_CG_EVENT_FLAG_MASK_ALTERNATE != 0 {
+            modifiers = modifiers | Modifiers::ALT;
+        }
+        if flags & K_CG_EVENT_FLAG_MASK_COMMAND != 0 {
+            modifiers = modifiers | Modifiers::META;
+        }
+        Ok(modifiers)
     }
-    
-    fn release_key(&self, _key: &str) -> MoverResult<()> {
-        unimplemented!("macOS keyboard release key not yet implemented")
+
+    fn is_key_pressed(&self, key: &str) -> MoverResult<bool> {
+        let (keycode, _needs_shift) = key_to_keycode(key)?;
+        Ok(unsafe { CGEventSourceKeyState(K_CG_EVENT_SOURCE_STATE_HID_SYSTEM_STATE, keycode) })
     }
-    
-    fn hold_key(&self, _key: &str) -> MoverResult<()> {
-        unimplemented!("macOS keyboard hold key not yet implemented")
+}
+
+impl super::CapturePlatform for MacOSPlatform {
+    fn start_capture(&self, _sink: Box<dyn FnMut(crate::Event) + Send>) -> MoverResult<()> {
+        // A real implementation needs a `CGEventTap` (`CGEventTapCreate` with
+        // `kCGEventTapOptionListenOnly`) added to the current run loop, which
+        // requires spinning a `CFRunLoop` for as long as the capture runs -
+        // a bigger piece of plumbing than the rest of this backend, left for
+        // follow-up work. Returning an error here (instead of panicking via
+        // `unimplemented!()`) lets callers like `mover_utils::ActionRecorder`
+        // that run this on a background thread surface the failure cleanly
+        // instead of the thread dying with an unhandled panic.
+        Err(crate::MoverError::PlatformError(
+            crate::PlatformError::UnsupportedOperation(
+                "macOS global input capture (CGEventTap) not yet implemented".to_string(),
+            ),
+        ))
     }
-    
-    fn press_keys(&self, _keys: &[&str]) -> MoverResult<()> {
-        unimplemented!("macOS keyboard press keys not yet implemented")
+
+    fn stop_capture(&self) -> MoverResult<()> {
+        Err(crate::MoverError::PlatformError(
+            crate::PlatformError::UnsupportedOperation(
+                "macOS global input capture not yet implemented".to_string(),
+            ),
+        ))
     }
-    
-    fn press_hotkey(&self, _keys: &[&str]) -> MoverResult<()> {
-        unimplemented!("macOS keyboard hotkey not yet implemented")
+}
+
+impl crate::InputListener for MacOSPlatform {
+    fn new_listener() -> MoverResult<Self> {
+        // Reuses `new`'s `AXIsProcessTrusted` check - gesture listening posts
+        // no synthetic events itself, but the `CGEventTap` it is built on
+        // needs the same Accessibility permission as everything else here.
+        Self::new()
+    }
+
+    fn start(&self, mut callback: Box<dyn FnMut(crate::listener::MouseEvent) + Send>) -> MoverResult<()> {
+        let mut decoder = crate::listener::GestureDecoder::new();
+        <Self as super::CapturePlatform>::start_capture(self, Box::new(move |event| {
+            for gesture in decoder.feed(&event) {
+                callback(gesture);
+            }
+        }))
+    }
+
+    fn stop(&self) -> MoverResult<()> {
+        <Self as super::CapturePlatform>::stop_capture(self)
     }
 }
 
@@ -126,8 +845,10 @@ impl super::Platform for MacOSPlatform {
     fn name(&self) -> &'static str {
         "macOS"
     }
-    
-    fn supports_feature(&self, _feature: &str) -> bool {
-        false // No features supported yet
+
+    fn supports_feature(&self, feature: &str) -> bool {
+        // "capture" is deliberately absent here: `CapturePlatform` above is
+        // not implemented yet, so it falls through to `false`.
+        matches!(feature, "accessibility_trusted")
     }
 }
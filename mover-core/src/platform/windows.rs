@@ -3,7 +3,7 @@
 //! This module will contain the actual Windows API implementation.
 //! Currently a placeholder for future development.
 
-use crate::{MoverResult, Point, Size, MouseButton, TweenFn};
+use crate::{CaptureFormat, Display, MoverResult, Point, Size, MouseButton, MouseCursor, ScrollDelta, TweenFn};
 
 /// Windows platform implementation
 pub struct WindowsPlatform;
@@ -65,12 +65,33 @@ impl super::MousePlatform for WindowsPlatform {
         unimplemented!("Windows mouse drag by not yet implemented")
     }
     
-    fn scroll_vertical(&self, _clicks: i32) -> MoverResult<()> {
-        unimplemented!("Windows vertical scroll not yet implemented")
+    fn scroll(&self, _delta: ScrollDelta) -> MoverResult<()> {
+        unimplemented!("Windows scroll not yet implemented")
     }
-    
-    fn scroll_horizontal(&self, _clicks: i32) -> MoverResult<()> {
-        unimplemented!("Windows horizontal scroll not yet implemented")
+
+    fn set_cursor(&self, _cursor: MouseCursor) -> MoverResult<()> {
+        unimplemented!("Windows cursor shape not yet implemented")
+    }
+
+    fn get_cursor(&self) -> MoverResult<MouseCursor> {
+        unimplemented!("Windows cursor shape not yet implemented")
+    }
+
+    fn is_button_pressed(&self, _button: MouseButton) -> MoverResult<bool> {
+        unimplemented!("Windows mouse button state query not yet implemented")
+    }
+
+    fn is_button_swapped(&self) -> MoverResult<bool> {
+        // `SPI_GETMOUSEBUTTONSWAP` needs a `user32` binding this workspace
+        // doesn't have yet - report it as unsupported rather than guessing,
+        // so callers that only need a safe default (see
+        // `mover_mouse::convert_button`) can fall back instead of panicking
+        // on valid input.
+        Err(crate::MoverError::PlatformError(
+            crate::PlatformError::UnsupportedOperation(
+                "Windows mouse button swap setting not yet implemented".to_string(),
+            ),
+        ))
     }
 }
 
@@ -83,17 +104,25 @@ impl super::ScreenPlatform for WindowsPlatform {
         unimplemented!("Windows on screen check not yet implemented")
     }
     
-    fn capture_screen(&self) -> MoverResult<Vec<u8>> {
+    fn capture_screen(&self) -> MoverResult<(Vec<u8>, CaptureFormat)> {
         unimplemented!("Windows screen capture not yet implemented")
     }
-    
-    fn capture_region(&self, _x: i32, _y: i32, _width: u32, _height: u32) -> MoverResult<Vec<u8>> {
+
+    fn capture_region(&self, _x: i32, _y: i32, _width: u32, _height: u32) -> MoverResult<(Vec<u8>, CaptureFormat)> {
         unimplemented!("Windows region capture not yet implemented")
     }
     
     fn get_pixel_color(&self, _x: i32, _y: i32) -> MoverResult<(u8, u8, u8)> {
         unimplemented!("Windows pixel color not yet implemented")
     }
+
+    fn scale_factor(&self) -> MoverResult<f64> {
+        unimplemented!("Windows scale factor not yet implemented")
+    }
+
+    fn displays(&self) -> MoverResult<Vec<Display>> {
+        unimplemented!("Windows display enumeration not yet implemented")
+    }
 }
 
 impl super::KeyboardPlatform for WindowsPlatform {
@@ -120,13 +149,43 @@ impl super::KeyboardPlatform for WindowsPlatform {
     fn press_hotkey(&self, _keys: &[&str]) -> MoverResult<()> {
         unimplemented!("Windows keyboard hotkey not yet implemented")
     }
+
+    fn get_modifiers(&self) -> MoverResult<crate::Modifiers> {
+        unimplemented!("Windows modifier state query not yet implemented")
+    }
+
+    fn is_key_pressed(&self, _key: &str) -> MoverResult<bool> {
+        unimplemented!("Windows key state query not yet implemented")
+    }
+}
+
+// Returning an error (instead of panicking via `unimplemented!()`) lets
+// callers like `mover_utils::ActionRecorder` that run this on a background
+// thread surface the failure cleanly instead of the thread dying with an
+// unhandled panic.
+impl super::CapturePlatform for WindowsPlatform {
+    fn start_capture(&self, _sink: Box<dyn FnMut(crate::Event) + Send>) -> MoverResult<()> {
+        Err(crate::MoverError::PlatformError(
+            crate::PlatformError::UnsupportedOperation(
+                "Windows global input capture (WH_MOUSE_LL/WH_KEYBOARD_LL) not yet implemented".to_string(),
+            ),
+        ))
+    }
+
+    fn stop_capture(&self) -> MoverResult<()> {
+        Err(crate::MoverError::PlatformError(
+            crate::PlatformError::UnsupportedOperation(
+                "Windows global input capture not yet implemented".to_string(),
+            ),
+        ))
+    }
 }
 
 impl super::Platform for WindowsPlatform {
     fn name(&self) -> &'static str {
         "Windows"
     }
-    
+
     fn supports_feature(&self, _feature: &str) -> bool {
         false // No features supported yet
     }
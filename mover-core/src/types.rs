@@ -90,6 +90,174 @@ impl fmt::Display for Size {
     }
 }
 
+/// A rectangular region of the screen, given as a top-left origin and size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Region {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Region {
+    /// Create a new region with the given origin and size.
+    pub fn new(x: i32, y: i32, width: u32, height: u32) -> Self {
+        Self { x, y, width, height }
+    }
+
+    /// The region's top-left corner.
+    pub fn origin(&self) -> Point {
+        Point::new(self.x, self.y)
+    }
+
+    /// The region's center point.
+    pub fn center(&self) -> Point {
+        Point::new(self.x + (self.width / 2) as i32, self.y + (self.height / 2) as i32)
+    }
+
+    /// Whether this region overlaps another.
+    pub fn overlaps(&self, other: &Region) -> bool {
+        self.x < other.x + other.width as i32
+            && other.x < self.x + self.width as i32
+            && self.y < other.y + other.height as i32
+            && other.y < self.y + self.height as i32
+    }
+
+    /// Whether `point` falls within this region.
+    pub fn contains(&self, point: &Point) -> bool {
+        point.x >= self.x
+            && point.x < self.x + self.width as i32
+            && point.y >= self.y
+            && point.y < self.y + self.height as i32
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {}x{})", self.x, self.y, self.width, self.height)
+    }
+}
+
+/// A stable identifier for a physical display, as returned by
+/// `ScreenPlatform::displays`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisplayId(pub u32);
+
+/// A physical display attached to the system.
+///
+/// `bounds` is given in global virtual-desktop coordinates, so secondary
+/// monitors positioned left of or above the primary display have negative
+/// `x`/`y`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Display {
+    pub id: DisplayId,
+    pub bounds: Region,
+    pub scale_factor: f64,
+    pub is_primary: bool,
+}
+
+/// Pixel layout of a raw screen-capture buffer, as produced by the
+/// platform's native capture API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PixelFormat {
+    /// 8-bit blue, green, red, alpha per pixel (Windows GDI, macOS CoreGraphics).
+    Bgra8,
+    /// 8-bit red, green, blue, alpha per pixel.
+    Rgba8,
+    /// 8-bit red, green, blue per pixel, with no alpha channel.
+    Rgb8,
+}
+
+impl PixelFormat {
+    /// Bytes occupied by a single pixel in this format.
+    pub fn bytes_per_pixel(&self) -> usize {
+        match self {
+            PixelFormat::Bgra8 | PixelFormat::Rgba8 => 4,
+            PixelFormat::Rgb8 => 3,
+        }
+    }
+}
+
+/// Describes the raw byte layout of a captured screen buffer, so it can be
+/// decoded into an `RgbaImage` without guessing the platform's conventions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CaptureFormat {
+    pub pixel_format: PixelFormat,
+    pub width: u32,
+    pub height: u32,
+    /// Bytes per row. May exceed `width * pixel_format.bytes_per_pixel()`
+    /// when the platform pads rows to an alignment boundary.
+    pub stride: u32,
+    /// Whether row `0` of the buffer is the top of the image (`true`) or the
+    /// bottom (`false`, as in traditional bottom-up Windows DIBs).
+    pub top_down: bool,
+}
+
+impl CaptureFormat {
+    /// A tightly packed (no row padding), top-down buffer in `pixel_format`.
+    pub fn packed(pixel_format: PixelFormat, width: u32, height: u32) -> Self {
+        Self {
+            pixel_format,
+            width,
+            height,
+            stride: width * pixel_format.bytes_per_pixel() as u32,
+            top_down: true,
+        }
+    }
+}
+
+/// Direction for a mouse's "navigate" side buttons (X1/X2 on Windows).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NavigationDirection {
+    /// Navigate backward (Windows `XBUTTON1`).
+    Back,
+    /// Navigate forward (Windows `XBUTTON2`).
+    Forward,
+}
+
+impl fmt::Display for NavigationDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NavigationDirection::Back => write!(f, "back"),
+            NavigationDirection::Forward => write!(f, "forward"),
+        }
+    }
+}
+
+/// The visible shape of the mouse cursor, mapped onto each platform's native
+/// cursor APIs (`XcursorLibraryLoadCursor` on X11, `SetCursor`/`LoadCursor` on
+/// Windows, `NSCursor` on macOS).
+///
+/// Not every platform can honor every shape; see `MousePlatform::set_cursor`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MouseCursor {
+    Arrow,
+    Hand,
+    Text,
+    Crosshair,
+    Wait,
+    Progress,
+    NotAllowed,
+    ResizeNS,
+    ResizeEW,
+    ResizeNWSE,
+    ResizeNESW,
+    Grab,
+    Grabbing,
+    Hidden,
+}
+
+impl Default for MouseCursor {
+    fn default() -> Self {
+        MouseCursor::Arrow
+    }
+}
+
 /// Mouse button types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -103,6 +271,9 @@ pub enum MouseButton {
     Button5,
     Button6,
     Button7,
+    /// A browser/file-manager "navigate back/forward" side button
+    /// (`XBUTTON1`/`XBUTTON2` on Windows).
+    Navigate(NavigationDirection),
 }
 
 impl MouseButton {
@@ -118,6 +289,19 @@ impl MouseButton {
             MouseButton::Button5 => 5,
             MouseButton::Button6 => 6,
             MouseButton::Button7 => 7,
+            MouseButton::Navigate(NavigationDirection::Back) => 8,
+            MouseButton::Navigate(NavigationDirection::Forward) => 9,
+        }
+    }
+
+    /// Resolves `Primary`/`Secondary` to the physical `Left`/`Right` button,
+    /// honoring the OS's button-swap setting for left-handed use. Every other
+    /// variant is returned unchanged.
+    pub fn resolve(&self, swapped: bool) -> MouseButton {
+        match self {
+            MouseButton::Primary => if swapped { MouseButton::Right } else { MouseButton::Left },
+            MouseButton::Secondary => if swapped { MouseButton::Left } else { MouseButton::Right },
+            other => *other,
         }
     }
 
@@ -125,18 +309,20 @@ impl MouseButton {
     pub fn is_valid_for_platform(&self) -> bool {
         #[cfg(target_os = "linux")]
         {
-            matches!(self, 
+            matches!(self,
                 MouseButton::Left | MouseButton::Middle | MouseButton::Right |
                 MouseButton::Primary | MouseButton::Secondary |
-                MouseButton::Button4 | MouseButton::Button5 | MouseButton::Button6 | MouseButton::Button7
+                MouseButton::Button4 | MouseButton::Button5 | MouseButton::Button6 | MouseButton::Button7 |
+                MouseButton::Navigate(_)
             )
         }
-        
+
         #[cfg(any(target_os = "windows", target_os = "macos"))]
         {
-            matches!(self, 
+            matches!(self,
                 MouseButton::Left | MouseButton::Middle | MouseButton::Right |
-                MouseButton::Primary | MouseButton::Secondary
+                MouseButton::Primary | MouseButton::Secondary |
+                MouseButton::Navigate(_)
             )
         }
     }
@@ -160,6 +346,7 @@ impl fmt::Display for MouseButton {
             MouseButton::Button5 => write!(f, "button5"),
             MouseButton::Button6 => write!(f, "button6"),
             MouseButton::Button7 => write!(f, "button7"),
+            MouseButton::Navigate(direction) => write!(f, "navigate-{}", direction),
         }
     }
 }
@@ -195,6 +382,159 @@ impl fmt::Display for ScrollDirection {
     }
 }
 
+/// The number of device units (on Windows, `WHEEL_DELTA`) that make up a single
+/// "line" of scrolling, used to approximate pixel-precision scrolling on
+/// backends that only support line/click scrolling.
+pub const WHEEL_DELTA: f32 = 120.0;
+
+/// A scroll amount, expressed either in discrete lines (the traditional
+/// mouse-wheel "click" unit) or in pixels for high-resolution trackpads and
+/// precision mice.
+///
+/// Not every platform can emit pixel-precision wheel events; see
+/// [`ScrollDelta::to_lines`] for the fallback used in that case.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScrollDelta {
+    /// Scroll by a number of wheel "lines" (what `clicks` meant historically).
+    Lines { x: f32, y: f32 },
+    /// Scroll by a number of pixels, for high-resolution scrolling surfaces.
+    Pixels { x: f32, y: f32 },
+}
+
+impl ScrollDelta {
+    /// Converts this delta to a line-based one, approximating pixels using
+    /// [`WHEEL_DELTA`] units per line on platforms that can't emit
+    /// pixel-precision wheel events directly.
+    pub fn to_lines(self) -> (f32, f32) {
+        match self {
+            ScrollDelta::Lines { x, y } => (x, y),
+            ScrollDelta::Pixels { x, y } => (x / WHEEL_DELTA, y / WHEEL_DELTA),
+        }
+    }
+}
+
+/// A modifier key that can be held around another action (e.g. ctrl-click, shift-drag).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Modifier {
+    Shift,
+    Control,
+    Alt,
+    Meta,
+}
+
+impl Modifier {
+    /// The key name this modifier presses, matching the names accepted by
+    /// `mover_keyboard::Keyboard::press_key`.
+    pub fn key_name(&self) -> &'static str {
+        match self {
+            Modifier::Shift => "shift",
+            Modifier::Control => "ctrl",
+            Modifier::Alt => "alt",
+            Modifier::Meta => "meta",
+        }
+    }
+}
+
+impl fmt::Display for Modifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.key_name())
+    }
+}
+
+/// A bitflag set of keyboard modifiers that can be held during another
+/// action (a shift-click, a ctrl-drag, a meta-scroll), modeled like
+/// `keyboard_types::Modifiers`. Unlike `Modifier`, which names a single key,
+/// `Modifiers` is a combinable set - use `Modifiers::CTRL | Modifiers::SHIFT`
+/// to ask for both at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Modifiers(u8);
+
+impl Modifiers {
+    pub const NONE: Modifiers = Modifiers(0);
+    pub const SHIFT: Modifiers = Modifiers(1 << 0);
+    pub const CTRL: Modifiers = Modifiers(1 << 1);
+    pub const ALT: Modifiers = Modifiers(1 << 2);
+    pub const META: Modifiers = Modifiers(1 << 3);
+    /// Alias for [`Modifiers::META`], matching the "Super" key name used by
+    /// Linux window managers.
+    pub const SUPER: Modifiers = Modifiers::META;
+
+    /// Whether every bit set in `other` is also set in `self`.
+    pub fn contains(&self, other: Modifiers) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Combines two modifier sets.
+    pub fn union(self, other: Modifiers) -> Modifiers {
+        Modifiers(self.0 | other.0)
+    }
+
+    /// Whether no modifier bit is set.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The individual `Modifier` keys set in this flag set, in the stable
+    /// press order used by `mover_mouse`'s modifier-aware actions
+    /// (ctrl, alt, shift, meta).
+    pub fn keys(&self) -> Vec<Modifier> {
+        let mut keys = Vec::new();
+        if self.contains(Modifiers::CTRL) {
+            keys.push(Modifier::Control);
+        }
+        if self.contains(Modifiers::ALT) {
+            keys.push(Modifier::Alt);
+        }
+        if self.contains(Modifiers::SHIFT) {
+            keys.push(Modifier::Shift);
+        }
+        if self.contains(Modifiers::META) {
+            keys.push(Modifier::Meta);
+        }
+        keys
+    }
+}
+
+impl std::ops::BitOr for Modifiers {
+    type Output = Modifiers;
+
+    fn bitor(self, rhs: Modifiers) -> Modifiers {
+        self.union(rhs)
+    }
+}
+
+impl From<Modifier> for Modifiers {
+    fn from(modifier: Modifier) -> Modifiers {
+        match modifier {
+            Modifier::Shift => Modifiers::SHIFT,
+            Modifier::Control => Modifiers::CTRL,
+            Modifier::Alt => Modifiers::ALT,
+            Modifier::Meta => Modifiers::META,
+        }
+    }
+}
+
+impl From<&[Modifier]> for Modifiers {
+    fn from(modifiers: &[Modifier]) -> Modifiers {
+        modifiers.iter().fold(Modifiers::NONE, |acc, m| acc.union((*m).into()))
+    }
+}
+
+impl<const N: usize> From<[Modifier; N]> for Modifiers {
+    fn from(modifiers: [Modifier; N]) -> Modifiers {
+        Modifiers::from(modifiers.as_slice())
+    }
+}
+
+impl<const N: usize> From<&[Modifier; N]> for Modifiers {
+    fn from(modifiers: &[Modifier; N]) -> Modifiers {
+        Modifiers::from(modifiers.as_slice())
+    }
+}
+
 /// Tweening function for smooth mouse movements
 pub type TweenFn = fn(f64) -> f64;
 
@@ -221,3 +561,268 @@ pub fn ease_in_out_quad(t: f64) -> f64 {
         -1.0 + (4.0 - 2.0 * t) * t
     }
 }
+
+/// Ease-in cubic tweening
+pub fn ease_in_cubic(t: f64) -> f64 {
+    t * t * t
+}
+
+/// Ease-out cubic tweening
+pub fn ease_out_cubic(t: f64) -> f64 {
+    let u = t - 1.0;
+    u * u * u + 1.0
+}
+
+/// Ease-in-out cubic tweening
+pub fn ease_in_out_cubic(t: f64) -> f64 {
+    if t < 0.5 {
+        4.0 * t * t * t
+    } else {
+        let u = -2.0 * t + 2.0;
+        1.0 - u * u * u / 2.0
+    }
+}
+
+/// Ease-in quartic tweening
+pub fn ease_in_quart(t: f64) -> f64 {
+    t * t * t * t
+}
+
+/// Ease-out quartic tweening
+pub fn ease_out_quart(t: f64) -> f64 {
+    let u = t - 1.0;
+    1.0 - u * u * u * u
+}
+
+/// Ease-in-out quartic tweening
+pub fn ease_in_out_quart(t: f64) -> f64 {
+    if t < 0.5 {
+        8.0 * t * t * t * t
+    } else {
+        let u = -2.0 * t + 2.0;
+        1.0 - u * u * u * u / 2.0
+    }
+}
+
+/// Ease-in quintic tweening
+pub fn ease_in_quint(t: f64) -> f64 {
+    t * t * t * t * t
+}
+
+/// Ease-out quintic tweening
+pub fn ease_out_quint(t: f64) -> f64 {
+    let u = t - 1.0;
+    1.0 + u * u * u * u * u
+}
+
+/// Ease-in-out quintic tweening
+pub fn ease_in_out_quint(t: f64) -> f64 {
+    if t < 0.5 {
+        16.0 * t * t * t * t * t
+    } else {
+        let u = -2.0 * t + 2.0;
+        1.0 - u * u * u * u * u / 2.0
+    }
+}
+
+/// Ease-in sine tweening
+pub fn ease_in_sine(t: f64) -> f64 {
+    1.0 - (t * std::f64::consts::FRAC_PI_2).cos()
+}
+
+/// Ease-out sine tweening
+pub fn ease_out_sine(t: f64) -> f64 {
+    (t * std::f64::consts::FRAC_PI_2).sin()
+}
+
+/// Ease-in-out sine tweening
+pub fn ease_in_out_sine(t: f64) -> f64 {
+    -((std::f64::consts::PI * t).cos() - 1.0) / 2.0
+}
+
+/// Ease-in exponential tweening
+pub fn ease_in_expo(t: f64) -> f64 {
+    if t <= 0.0 {
+        0.0
+    } else {
+        2.0_f64.powf(10.0 * t - 10.0)
+    }
+}
+
+/// Ease-out exponential tweening
+pub fn ease_out_expo(t: f64) -> f64 {
+    if t >= 1.0 {
+        1.0
+    } else {
+        1.0 - 2.0_f64.powf(-10.0 * t)
+    }
+}
+
+/// Ease-in-out exponential tweening
+pub fn ease_in_out_expo(t: f64) -> f64 {
+    if t <= 0.0 {
+        0.0
+    } else if t >= 1.0 {
+        1.0
+    } else if t < 0.5 {
+        2.0_f64.powf(20.0 * t - 10.0) / 2.0
+    } else {
+        (2.0 - 2.0_f64.powf(-20.0 * t + 10.0)) / 2.0
+    }
+}
+
+/// Ease-in circular tweening
+pub fn ease_in_circ(t: f64) -> f64 {
+    1.0 - (1.0 - t * t).sqrt()
+}
+
+/// Ease-out circular tweening
+pub fn ease_out_circ(t: f64) -> f64 {
+    (1.0 - (t - 1.0) * (t - 1.0)).sqrt()
+}
+
+/// Ease-in-out circular tweening
+pub fn ease_in_out_circ(t: f64) -> f64 {
+    if t < 0.5 {
+        (1.0 - (1.0 - (2.0 * t).powi(2)).sqrt()) / 2.0
+    } else {
+        ((1.0 - (-2.0 * t + 2.0).powi(2)).sqrt() + 1.0) / 2.0
+    }
+}
+
+/// Ease-out "back" tweening - overshoots past `1.0` before settling, like an
+/// object overshooting its target and springing back.
+pub fn ease_out_back(t: f64) -> f64 {
+    const C1: f64 = 1.70158;
+    const C3: f64 = C1 + 1.0;
+    let u = t - 1.0;
+    1.0 + C3 * u * u * u + C1 * u * u
+}
+
+/// Ease-out elastic tweening - oscillates past `1.0` like a released spring
+/// before settling.
+pub fn ease_out_elastic(t: f64) -> f64 {
+    const C4: f64 = 2.0 * std::f64::consts::PI / 3.0;
+    if t <= 0.0 {
+        0.0
+    } else if t >= 1.0 {
+        1.0
+    } else {
+        2.0_f64.powf(-10.0 * t) * ((t * 10.0 - 0.75) * C4).sin() + 1.0
+    }
+}
+
+/// Ease-out "bounce" tweening - settles with a series of decaying bounces,
+/// like a dropped ball.
+pub fn ease_out_bounce(t: f64) -> f64 {
+    const N1: f64 = 7.5625;
+    const D1: f64 = 2.75;
+    if t < 1.0 / D1 {
+        N1 * t * t
+    } else if t < 2.0 / D1 {
+        let u = t - 1.5 / D1;
+        N1 * u * u + 0.75
+    } else if t < 2.5 / D1 {
+        let u = t - 2.25 / D1;
+        N1 * u * u + 0.9375
+    } else {
+        let u = t - 2.625 / D1;
+        N1 * u * u + 0.984375
+    }
+}
+
+/// A CSS-style `cubic-bezier(x1, y1, x2, y2)` timing function, with control
+/// points `(0, 0)` and `(1, 1)` implicit.
+///
+/// Unlike the Penner easings above (plain `fn(f64) -> f64`, usable directly
+/// as a [`TweenFn`]), a cubic Bézier timing curve needs its own control
+/// points, so it's built as a small struct that produces a closure via
+/// [`CubicBezierTween::into_fn`] rather than being a bare function.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicBezierTween {
+    pub x1: f64,
+    pub y1: f64,
+    pub x2: f64,
+    pub y2: f64,
+}
+
+impl CubicBezierTween {
+    pub fn new(x1: f64, y1: f64, x2: f64, y2: f64) -> Self {
+        Self { x1, y1, x2, y2 }
+    }
+
+    /// Evaluates the timing curve at progress `t` (expected in `[0, 1]`).
+    ///
+    /// Solves `X(s) = t` for the Bézier parameter `s` via a few
+    /// Newton-Raphson iterations seeded at `s = t`, falling back to
+    /// bisection when the derivative is too close to zero to make progress,
+    /// then evaluates `Y(s)`.
+    pub fn ease(&self, t: f64) -> f64 {
+        if t <= 0.0 {
+            return 0.0;
+        }
+        if t >= 1.0 {
+            return 1.0;
+        }
+
+        let bezier = |a: f64, b: f64, s: f64| -> f64 {
+            let u = 1.0 - s;
+            3.0 * u * u * s * a + 3.0 * u * s * s * b + s * s * s
+        };
+        let bezier_derivative = |a: f64, b: f64, s: f64| -> f64 {
+            let u = 1.0 - s;
+            3.0 * u * u * a + 6.0 * u * s * (b - a) + 3.0 * s * s * (1.0 - b)
+        };
+
+        let mut s = t;
+        let mut found = false;
+        for _ in 0..8 {
+            let x = bezier(self.x1, self.x2, s) - t;
+            let dx = bezier_derivative(self.x1, self.x2, s);
+            if dx.abs() < 1e-6 {
+                found = false;
+                break;
+            }
+            s -= x / dx;
+            s = s.clamp(0.0, 1.0);
+            if x.abs() < 1e-7 {
+                found = true;
+                break;
+            }
+            found = true;
+        }
+
+        if !found {
+            // Newton-Raphson stalled (near-zero derivative) - fall back to
+            // bisection, which always converges even if more slowly.
+            let mut lo = 0.0_f64;
+            let mut hi = 1.0_f64;
+            s = t;
+            for _ in 0..20 {
+                let x = bezier(self.x1, self.x2, s);
+                if (x - t).abs() < 1e-7 {
+                    break;
+                }
+                if x < t {
+                    lo = s;
+                } else {
+                    hi = s;
+                }
+                s = (lo + hi) / 2.0;
+            }
+        }
+
+        bezier(self.y1, self.y2, s)
+    }
+
+    /// Turns this curve into a closure that evaluates
+    /// [`CubicBezierTween::ease`], for APIs that take any `Fn(f64) -> f64`.
+    ///
+    /// `TweenFn` is a bare `fn(f64) -> f64` pointer, which can't capture the
+    /// curve's control points, so callers that need a `TweenFn` should store
+    /// the `CubicBezierTween` itself and call [`CubicBezierTween::ease`]
+    /// directly instead.
+    pub fn into_fn(self) -> impl Fn(f64) -> f64 {
+        move |t| self.ease(t)
+    }
+}
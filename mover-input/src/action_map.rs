@@ -0,0 +1,227 @@
+//! Declarative action-map layer over the raw event listener.
+//!
+//! Where [`crate::listen`] hands callers a raw stream of [`crate::InputEvent`]s,
+//! `InputMap` lets callers register bindings mapping physical inputs (single
+//! keys, mouse buttons, or multi-key chords like `Ctrl+Shift+K`) to their own
+//! action enum, and reports which actions are just-pressed, held, or
+//! just-released each tick - the model used by most game-input and macro
+//! libraries instead of manual event matching.
+
+use crate::InputEvent;
+use mover_core::MouseButton;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// A single physical input that can participate in a [`Binding`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Input {
+    /// A keyboard key, named the same way as `mover_keyboard::Keyboard::press_key`.
+    Key(String),
+    /// A mouse button.
+    Button(MouseButton),
+}
+
+/// A set of [`Input`]s that must all be held at once to fire - a chord.
+///
+/// A binding with a single input (e.g. just `C`) is simply a chord of length one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Binding {
+    inputs: Vec<Input>,
+}
+
+impl Binding {
+    /// Creates a binding requiring every input in `inputs` to be held.
+    pub fn chord(inputs: impl IntoIterator<Item = Input>) -> Self {
+        let mut inputs: Vec<Input> = inputs.into_iter().collect();
+        inputs.sort_by_key(|i| format!("{:?}", i));
+        inputs.dedup();
+        Self { inputs }
+    }
+
+    /// Creates a single-input binding.
+    pub fn single(input: Input) -> Self {
+        Self::chord([input])
+    }
+
+    /// Number of inputs required by this binding - longer chords are more specific.
+    pub fn len(&self) -> usize {
+        self.inputs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inputs.is_empty()
+    }
+
+    fn is_satisfied_by(&self, held: &HashSet<Input>) -> bool {
+        !self.inputs.is_empty() && self.inputs.iter().all(|i| held.contains(i))
+    }
+
+    fn is_strict_subset_of(&self, other: &Binding) -> bool {
+        self.len() < other.len() && self.inputs.iter().all(|i| other.inputs.contains(i))
+    }
+}
+
+/// Trait bound satisfied by user-defined action enums/types.
+pub trait Action: Clone + Eq + Hash {}
+impl<T: Clone + Eq + Hash> Action for T {}
+
+/// How to resolve multiple bindings matching at once (e.g. `C`, `Ctrl+C`, and
+/// `Ctrl+Shift+C` all held down together).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClashPolicy {
+    /// The longest matching chord wins, suppressing any binding whose
+    /// required inputs are a strict subset of another matched binding.
+    PrioritizeLongest,
+    /// Every matching binding fires, regardless of overlap.
+    AllowAll,
+}
+
+/// Which actions just changed state, and which are currently held, this tick.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActionState<A: Action> {
+    pub just_pressed: HashSet<A>,
+    pub held: HashSet<A>,
+    pub just_released: HashSet<A>,
+}
+
+impl<A: Action> Default for ActionState<A> {
+    fn default() -> Self {
+        Self {
+            just_pressed: HashSet::new(),
+            held: HashSet::new(),
+            just_released: HashSet::new(),
+        }
+    }
+}
+
+/// Maps physical inputs (keys, buttons, chords) to user-defined actions.
+pub struct InputMap<A: Action> {
+    bindings: Vec<(Binding, A)>,
+    policy: ClashPolicy,
+    held_inputs: HashSet<Input>,
+    held_actions: HashSet<A>,
+}
+
+impl<A: Action> InputMap<A> {
+    /// Creates an empty input map using the given clash resolution policy.
+    pub fn new(policy: ClashPolicy) -> Self {
+        Self {
+            bindings: Vec::new(),
+            policy,
+            held_inputs: HashSet::new(),
+            held_actions: HashSet::new(),
+        }
+    }
+
+    /// Registers `action` to fire whenever `binding`'s inputs are all held.
+    pub fn bind(&mut self, binding: Binding, action: A) -> &mut Self {
+        self.bindings.push((binding, action));
+        self
+    }
+
+    /// Feeds a raw input event into the map, updating which physical inputs
+    /// are currently held. Call [`InputMap::tick`] afterward to get the
+    /// resulting [`ActionState`].
+    pub fn process_event(&mut self, event: &InputEvent) {
+        match event {
+            InputEvent::ButtonPress(button) => {
+                self.held_inputs.insert(Input::Button(*button));
+            }
+            InputEvent::ButtonRelease(button) => {
+                self.held_inputs.remove(&Input::Button(*button));
+            }
+            InputEvent::KeyPress(key) => {
+                self.held_inputs.insert(Input::Key(mover_keyboard::describe_key(*key)));
+            }
+            InputEvent::KeyRelease(key) => {
+                self.held_inputs.remove(&Input::Key(mover_keyboard::describe_key(*key)));
+            }
+            InputEvent::MouseMove { .. } | InputEvent::Scroll { .. } => {}
+        }
+    }
+
+    /// Computes this tick's [`ActionState`] from the inputs currently held,
+    /// applying the map's [`ClashPolicy`], and diffing against the previous
+    /// tick to determine just-pressed/just-released actions.
+    pub fn tick(&mut self) -> ActionState<A> {
+        let matched_bindings: Vec<&(Binding, A)> = self
+            .bindings
+            .iter()
+            .filter(|(binding, _)| binding.is_satisfied_by(&self.held_inputs))
+            .collect();
+
+        let surviving: Vec<&(Binding, A)> = match self.policy {
+            ClashPolicy::AllowAll => matched_bindings,
+            ClashPolicy::PrioritizeLongest => matched_bindings
+                .iter()
+                .filter(|(candidate, _)| {
+                    !matched_bindings
+                        .iter()
+                        .any(|(other, _)| candidate.is_strict_subset_of(other))
+                })
+                .copied()
+                .collect(),
+        };
+
+        let held: HashSet<A> = surviving.into_iter().map(|(_, action)| action.clone()).collect();
+
+        let just_pressed = held.difference(&self.held_actions).cloned().collect();
+        let just_released = self.held_actions.difference(&held).cloned().collect();
+
+        self.held_actions = held.clone();
+
+        ActionState {
+            just_pressed,
+            held,
+            just_released,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum TestAction {
+        Copy,
+        Quit,
+    }
+
+    #[test]
+    fn test_single_key_binding_reports_just_pressed_and_held() {
+        let mut map = InputMap::new(ClashPolicy::PrioritizeLongest);
+        map.bind(Binding::single(Input::Key("a".to_string())), TestAction::Quit);
+
+        map.process_event(&InputEvent::KeyPress(enigo::Key::Unicode('a')));
+        let state = map.tick();
+        assert!(state.just_pressed.contains(&TestAction::Quit));
+        assert!(state.held.contains(&TestAction::Quit));
+
+        let state = map.tick();
+        assert!(!state.just_pressed.contains(&TestAction::Quit));
+        assert!(state.held.contains(&TestAction::Quit));
+
+        map.process_event(&InputEvent::KeyRelease(enigo::Key::Unicode('a')));
+        let state = map.tick();
+        assert!(!state.held.contains(&TestAction::Quit));
+        assert!(state.just_released.contains(&TestAction::Quit));
+    }
+
+    #[test]
+    fn test_chord_binding_requires_every_key_held() {
+        let mut map = InputMap::new(ClashPolicy::PrioritizeLongest);
+        map.bind(
+            Binding::chord([Input::Key("ctrl".to_string()), Input::Key("c".to_string())]),
+            TestAction::Copy,
+        );
+
+        map.process_event(&InputEvent::KeyPress(enigo::Key::Control));
+        let state = map.tick();
+        assert!(state.held.is_empty(), "chord should not fire with only one of its keys held");
+
+        map.process_event(&InputEvent::KeyPress(enigo::Key::Unicode('c')));
+        let state = map.tick();
+        assert!(state.just_pressed.contains(&TestAction::Copy));
+    }
+}
@@ -0,0 +1,161 @@
+//! Input-state querying and global event-listening for the mover automation library
+//!
+//! Where `mover-mouse` and `mover-keyboard` only synthesize output (`move_to`,
+//! `left_click`, `press_key`, ...), this module provides the read side: querying
+//! whether a key or button is currently held down, reading the full mouse state,
+//! and listening to a global stream of input events as they occur on the system.
+//!
+//! This mirrors the `GetMouse`/`TestKey`/`TestMouse` primitives found in other
+//! automation backends (e.g. the crsn screen backend) and is the foundation for
+//! recording, hotkeys, and action maps built on top of this crate.
+//!
+//! # Quick Start
+//!
+//! ```rust,no_run
+//! use mover_input::{is_key_pressed, mouse_state};
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     if is_key_pressed("ctrl")? {
+//!         println!("Ctrl is currently held down");
+//!     }
+//!
+//!     let state = mouse_state()?;
+//!     println!("Mouse is at {} with {} button(s) held", state.position, state.buttons.len());
+//!     Ok(())
+//! }
+//! ```
+//!
+//! ## Listening for Input
+//!
+//! ```rust,no_run
+//! use mover_input::{listen, ControlFlow, InputEvent};
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     listen(|event| {
+//!         match event {
+//!             InputEvent::KeyPress(key) => println!("Key pressed: {:?}", key),
+//!             InputEvent::ButtonPress(button) => println!("Button pressed: {}", button),
+//!             _ => {}
+//!         }
+//!         ControlFlow::Continue
+//!     })?;
+//!     Ok(())
+//! }
+//! ```
+
+use mover_core::{MouseButton, MoverError, MoverResult, PlatformError, Point};
+
+pub mod recording;
+pub use recording::*;
+
+pub mod action_map;
+pub use action_map::*;
+
+/// Re-export the key type used by the keyboard hook, matching the type
+/// `mover-keyboard` already converts string key names into internally.
+pub use enigo::Key;
+
+/// A single input event delivered to a [`listen`] handler.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputEvent {
+    /// The mouse moved to the given absolute coordinates.
+    MouseMove { x: i32, y: i32 },
+    /// A mouse button was pressed.
+    ButtonPress(MouseButton),
+    /// A mouse button was released.
+    ButtonRelease(MouseButton),
+    /// The scroll wheel moved by the given amount on each axis.
+    Scroll { dx: i32, dy: i32 },
+    /// A key was pressed.
+    KeyPress(Key),
+    /// A key was released.
+    KeyRelease(Key),
+}
+
+/// Tells [`listen`] whether to keep processing events or unhook and return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlFlow {
+    /// Keep the hook installed and keep delivering events.
+    Continue,
+    /// Unhook and return from `listen`.
+    Break,
+}
+
+/// Snapshot of the mouse: its position and every button currently held down.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MouseState {
+    /// Current cursor position.
+    pub position: Point,
+    /// Buttons currently held down, if any.
+    pub buttons: Vec<MouseButton>,
+}
+
+fn unsupported(operation: &str) -> MoverError {
+    MoverError::PlatformError(PlatformError::UnsupportedOperation(format!(
+        "{operation} is not yet implemented for platform: {}",
+        std::env::consts::OS
+    )))
+}
+
+/// Returns whether the given key is currently held down.
+///
+/// `key` uses the same key names accepted by `mover_keyboard::Keyboard::press_key`
+/// (e.g. `"ctrl"`, `"a"`, `"enter"`).
+///
+/// # Platform Notes
+///
+/// Backed by `KeyboardPlatform::is_key_pressed` (`CGEventSourceKeyState` on
+/// macOS); not yet implemented on Linux or Windows.
+pub fn is_key_pressed(key: &str) -> MoverResult<bool> {
+    mover_core::platform::get_platform()?.is_key_pressed(key)
+}
+
+/// Returns whether the given mouse button is currently held down.
+///
+/// # Platform Notes
+///
+/// Backed by `MousePlatform::is_button_pressed` (`CGEventSourceButtonState`
+/// on macOS); not yet implemented on Linux or Windows.
+pub fn is_button_pressed(button: MouseButton) -> MoverResult<bool> {
+    mover_core::platform::get_platform()?.is_button_pressed(button)
+}
+
+/// Returns the current mouse position plus the set of currently-held buttons.
+///
+/// # Platform Notes
+///
+/// Backed by `MousePlatform::get_position`/`is_button_pressed`; not yet
+/// implemented on Linux or Windows. Only the buttons with a direct
+/// `MouseButton` mapping on this platform (`Left`/`Right`/`Middle`) are
+/// probed - the `Navigate`/extra buttons are left out of the result rather
+/// than guessed at.
+pub fn mouse_state() -> MoverResult<MouseState> {
+    let platform = mover_core::platform::get_platform()?;
+    let position = platform.get_position()?;
+
+    let mut buttons = Vec::new();
+    for button in [MouseButton::Left, MouseButton::Right, MouseButton::Middle] {
+        if platform.is_button_pressed(button)? {
+            buttons.push(button);
+        }
+    }
+
+    Ok(MouseState { position, buttons })
+}
+
+/// Installs a global input hook and blocks the calling thread, delivering each
+/// [`InputEvent`] to `handler` as it occurs.
+///
+/// The hook stays installed until `handler` returns [`ControlFlow::Break`], at
+/// which point it is removed and `listen` returns.
+///
+/// # Platform Notes
+///
+/// Global input hooks are not yet implemented for any platform.
+pub fn listen<F>(mut handler: F) -> MoverResult<()>
+where
+    F: FnMut(InputEvent) -> ControlFlow,
+{
+    let _ = &mut handler;
+    Err(unsupported("listen"))
+}
@@ -0,0 +1,201 @@
+//! Record-and-replay of captured input sessions.
+//!
+//! Builds on [`crate::listen`] to capture a timed stream of [`crate::InputEvent`]s
+//! into a serializable [`Recording`], then [`replay`] walks the events back out
+//! through the existing `mover_mouse`/`mover_keyboard` output functions. This lets
+//! a user perform a workflow once and play it back deterministically.
+
+use crate::{listen, ControlFlow, InputEvent};
+use mover_core::{MouseButton, MoverError, MoverResult};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// A serializable mirror of [`InputEvent`].
+///
+/// `enigo::Key` doesn't implement `Serialize`, so key events are stored by their
+/// `Debug` representation instead (e.g. `"Unicode('a')"`, `"Control"`).
+///
+/// Recordings keep this `Debug` form even though
+/// [`mover_keyboard::describe_key`] gives a canonical name for a key.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RecordedEvent {
+    MouseMove { x: i32, y: i32 },
+    ButtonPress(MouseButton),
+    ButtonRelease(MouseButton),
+    Scroll { dx: i32, dy: i32 },
+    KeyPress(String),
+    KeyRelease(String),
+}
+
+impl From<&InputEvent> for RecordedEvent {
+    fn from(event: &InputEvent) -> Self {
+        match event {
+            InputEvent::MouseMove { x, y } => RecordedEvent::MouseMove { x: *x, y: *y },
+            InputEvent::ButtonPress(button) => RecordedEvent::ButtonPress(*button),
+            InputEvent::ButtonRelease(button) => RecordedEvent::ButtonRelease(*button),
+            InputEvent::Scroll { dx, dy } => RecordedEvent::Scroll { dx: *dx, dy: *dy },
+            InputEvent::KeyPress(key) => RecordedEvent::KeyPress(format!("{:?}", key)),
+            InputEvent::KeyRelease(key) => RecordedEvent::KeyRelease(format!("{:?}", key)),
+        }
+    }
+}
+
+/// A [`RecordedEvent`] plus how long after the recording started it occurred.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimedEvent {
+    pub event: RecordedEvent,
+    pub offset: Duration,
+}
+
+/// A captured sequence of timed input events.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Recording {
+    pub events: Vec<TimedEvent>,
+}
+
+impl Recording {
+    /// Creates an empty recording.
+    pub fn new() -> Self {
+        Self { events: Vec::new() }
+    }
+
+    /// Captures `listen`'s event stream into a `Recording`.
+    ///
+    /// `should_stop` is consulted after every event so callers can end the
+    /// capture on a particular key or click (e.g. Escape) in addition to
+    /// whatever `listen` itself supports.
+    pub fn capture(mut should_stop: impl FnMut(&InputEvent) -> bool) -> MoverResult<Self> {
+        let start = Instant::now();
+        let mut recording = Recording::new();
+
+        listen(|event| {
+            recording.events.push(TimedEvent {
+                event: RecordedEvent::from(&event),
+                offset: start.elapsed(),
+            });
+
+            if should_stop(&event) {
+                ControlFlow::Break
+            } else {
+                ControlFlow::Continue
+            }
+        })?;
+
+        Ok(recording)
+    }
+
+    /// Saves the recording as pretty-printed JSON to `path`.
+    pub fn save(&self, path: &Path) -> MoverResult<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| MoverError::Other(format!("Failed to serialize recording: {}", e)))?;
+        std::fs::write(path, json).map_err(MoverError::IoError)
+    }
+
+    /// Loads a recording previously written by [`Recording::save`].
+    pub fn load(path: &Path) -> MoverResult<Self> {
+        let json = std::fs::read_to_string(path).map_err(MoverError::IoError)?;
+        serde_json::from_str(&json)
+            .map_err(|e| MoverError::Other(format!("Failed to deserialize recording: {}", e)))
+    }
+}
+
+/// Speed multiplier applied to a recording's inter-event delays during replay.
+///
+/// `ReplaySpeed(2.0)` replays twice as fast (half the recorded delay);
+/// `ReplaySpeed(0.5)` replays at half speed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReplaySpeed(pub f64);
+
+impl ReplaySpeed {
+    /// Replay at the speed the events were originally recorded.
+    pub const NORMAL: ReplaySpeed = ReplaySpeed(1.0);
+}
+
+impl Default for ReplaySpeed {
+    fn default() -> Self {
+        Self::NORMAL
+    }
+}
+
+/// Replays a recording, reproducing each event via the existing output
+/// functions and honoring the recorded inter-event delays scaled by `speed`.
+pub fn replay(recording: &Recording, speed: ReplaySpeed) -> MoverResult<()> {
+    let mut mouse = mover_mouse::Mouse::new()
+        .map_err(|e| MoverError::Other(format!("Failed to create Mouse for replay: {}", e)))?;
+    let mut keyboard = mover_keyboard::Keyboard::new()
+        .map_err(|e| MoverError::Other(format!("Failed to create Keyboard for replay: {}", e)))?;
+
+    let mut previous_offset = Duration::ZERO;
+    for timed in &recording.events {
+        let delay = timed.offset.saturating_sub(previous_offset);
+        previous_offset = timed.offset;
+        if speed.0 > 0.0 {
+            std::thread::sleep(Duration::from_secs_f64(delay.as_secs_f64() / speed.0));
+        }
+
+        match &timed.event {
+            RecordedEvent::MouseMove { x, y } => mouse.move_to(*x, *y)?,
+            RecordedEvent::ButtonPress(button) => mouse.mouse_down(Some(*button))?,
+            RecordedEvent::ButtonRelease(button) => mouse.mouse_up(Some(*button))?,
+            RecordedEvent::Scroll { dx, dy } => {
+                if *dx != 0 {
+                    mouse.hscroll(*dx)?;
+                }
+                if *dy != 0 {
+                    mouse.vscroll(*dy)?;
+                }
+            }
+            RecordedEvent::KeyPress(key) => {
+                if let Some(name) = debug_key_to_key_name(key) {
+                    keyboard.press_key(&name)?;
+                }
+            }
+            RecordedEvent::KeyRelease(key) => {
+                if let Some(name) = debug_key_to_key_name(key) {
+                    keyboard.release_key(&name)?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Replays `recording` `n` times in a row, at the given `speed`.
+pub fn replay_loop(recording: &Recording, speed: ReplaySpeed, n: usize) -> MoverResult<()> {
+    for _ in 0..n {
+        replay(recording, speed)?;
+    }
+    Ok(())
+}
+
+/// Best-effort mapping from an `enigo::Key`'s `Debug` output back to the key
+/// names accepted by `mover_keyboard::Keyboard::press_key`.
+///
+/// This only covers the common cases (single characters and well-known named
+/// keys); anything else is dropped during replay.
+fn debug_key_to_key_name(debug: &str) -> Option<String> {
+    if let Some(rest) = debug.strip_prefix("Unicode('").and_then(|s| s.strip_suffix("')")) {
+        return Some(rest.to_string());
+    }
+
+    let name = match debug {
+        "Control" => "ctrl",
+        "Shift" => "shift",
+        "Alt" => "alt",
+        "Meta" => "meta",
+        "Return" => "enter",
+        "Space" => "space",
+        "Tab" => "tab",
+        "Escape" => "escape",
+        "Backspace" => "backspace",
+        "Delete" => "delete",
+        "UpArrow" => "up",
+        "DownArrow" => "down",
+        "LeftArrow" => "left",
+        "RightArrow" => "right",
+        _ => return None,
+    };
+    Some(name.to_string())
+}
@@ -73,7 +73,7 @@
 //! }
 //! ```
 
-use mover_core::MoverResult;
+use mover_core::{Key, MediaButton, MoverResult};
 use std::time::Duration;
 use std::thread;
 use enigo::{Enigo, Settings, Direction, Keyboard as EnigoKeyboard};
@@ -115,8 +115,155 @@ use enigo::{Enigo, Settings, Direction, Keyboard as EnigoKeyboard};
 /// - **Linux**: Full support, may require X11 or Wayland setup
 pub struct Keyboard {
     enigo: Enigo,
+    held_keys: std::collections::HashSet<Key>,
+    repeat_settings: RepeatSettings,
+    layout: KeyboardLayout,
+    aliases: std::collections::HashMap<String, String>,
 }
 
+/// Tunables for [`Keyboard::hold_key`]'s auto-repeat timing, mirroring how a
+/// physical keyboard waits `delay` after the initial press before repeating
+/// at a steady `rate`.
+#[derive(Debug, Clone, Copy)]
+pub struct RepeatSettings {
+    /// Seconds to wait after the initial press before repeating begins.
+    pub delay: f64,
+    /// Repeats per second once repeating has begun.
+    pub rate: f64,
+}
+
+impl Default for RepeatSettings {
+    fn default() -> Self {
+        Self {
+            delay: 0.5,
+            rate: 20.0,
+        }
+    }
+}
+
+/// A physical-to-logical keyboard layout mapping, for driving a machine
+/// whose physical layout differs from the characters the caller wants to
+/// produce.
+///
+/// Each built-in variant's table records, for every key that differs from
+/// QWERTY, which character that *physical* (QWERTY-labeled) key produces
+/// under the layout. [`Keyboard::with_layout`] uses the table in reverse:
+/// to type a logical character, it looks up which physical key yields it.
+#[derive(Debug, Clone)]
+pub enum KeyboardLayout {
+    /// The identity layout - logical and physical characters match.
+    Qwerty,
+    Dvorak,
+    Colemak,
+    /// A user-supplied physical-to-logical character map, e.g. loaded from
+    /// a TOML/JSON remap file.
+    Custom(std::collections::HashMap<char, char>),
+}
+
+/// (physical QWERTY char, character produced under the layout) pairs for
+/// every key that differs from QWERTY.
+const DVORAK_TABLE: &[(char, char)] = &[
+    ('q', '\''), ('w', ','), ('e', '.'), ('r', 'p'), ('t', 'y'),
+    ('y', 'f'), ('u', 'g'), ('i', 'c'), ('o', 'r'), ('p', 'l'),
+    ('s', 'o'), ('d', 'e'), ('f', 'u'), ('g', 'i'), ('h', 'd'),
+    ('j', 'h'), ('k', 't'), ('l', 'n'), (';', 's'),
+    ('z', ';'), ('x', 'q'), ('c', 'j'), ('v', 'k'), ('b', 'x'),
+    ('n', 'b'), (',', 'w'), ('.', 'v'), ('/', 'z'),
+    ('-', '['), ('=', ']'), ('[', '/'), (']', '='), ('\'', '-'),
+];
+
+const COLEMAK_TABLE: &[(char, char)] = &[
+    ('e', 'f'), ('r', 'p'), ('t', 'g'), ('y', 'j'), ('u', 'l'),
+    ('i', 'u'), ('o', 'y'), ('p', ';'),
+    ('s', 'r'), ('d', 's'), ('f', 't'), ('g', 'd'),
+    ('j', 'n'), ('k', 'e'), ('l', 'i'), (';', 'o'),
+    ('n', 'k'),
+];
+
+impl KeyboardLayout {
+    /// Returns the physical (QWERTY) character that must be pressed to
+    /// produce `logical_char` under this layout, or `logical_char`
+    /// unchanged if the layout has no mapping for it.
+    fn physical_char(&self, logical_char: char) -> char {
+        match self {
+            KeyboardLayout::Qwerty => logical_char,
+            KeyboardLayout::Dvorak => Self::inverse_lookup(DVORAK_TABLE, logical_char),
+            KeyboardLayout::Colemak => Self::inverse_lookup(COLEMAK_TABLE, logical_char),
+            KeyboardLayout::Custom(map) => map
+                .iter()
+                .find(|(_, &produced)| produced == logical_char)
+                .map(|(&physical, _)| physical)
+                .unwrap_or(logical_char),
+        }
+    }
+
+    fn inverse_lookup(table: &[(char, char)], logical_char: char) -> char {
+        table
+            .iter()
+            .find(|&&(_, produced)| produced == logical_char)
+            .map(|&(physical, _)| physical)
+            .unwrap_or(logical_char)
+    }
+
+    /// Whether this layout leaves every character unchanged, in which case
+    /// the fast `enigo.text()` typing path can be used as-is.
+    fn is_identity(&self) -> bool {
+        matches!(self, KeyboardLayout::Qwerty)
+    }
+}
+
+/// (shifted US-QWERTY character, its unshifted base character) pairs for
+/// every symbol that needs Shift held to produce it.
+const SHIFT_SYMBOL_TABLE: &[(char, char)] = &[
+    ('!', '1'), ('@', '2'), ('#', '3'), ('$', '4'), ('%', '5'),
+    ('^', '6'), ('&', '7'), ('*', '8'), ('(', '9'), (')', '0'),
+    ('_', '-'), ('+', '='),
+    ('{', '['), ('}', ']'), ('|', '\\'),
+    (':', ';'), ('"', '\''),
+    ('<', ','), ('>', '.'), ('?', '/'),
+    ('~', '`'),
+];
+
+/// Maps each character reachable from a base [`KeyboardLayout`] to the
+/// physical key that must be tapped and whether Shift must be held while
+/// tapping it, so callers don't have to rely on the backend/OS to infer
+/// shift state from a bare `Key::Unicode`.
+pub struct InverseKeymap {
+    layout: KeyboardLayout,
+}
+
+impl InverseKeymap {
+    /// Builds an inverse keymap for the given base layout.
+    pub fn new(layout: KeyboardLayout) -> Self {
+        Self { layout }
+    }
+
+    /// Looks up the `(physical char, needs_shift)` pair that produces `c`.
+    fn lookup(&self, c: char) -> (char, bool) {
+        let (base, needs_shift) = Self::unshift(c);
+        (self.layout.physical_char(base), needs_shift)
+    }
+
+    /// Splits a character into its unshifted base character and whether
+    /// Shift is needed to produce it, per standard US-QWERTY shift rules.
+    fn unshift(c: char) -> (char, bool) {
+        if c.is_ascii_uppercase() {
+            return (c.to_ascii_lowercase(), true);
+        }
+        for &(shifted, base) in SHIFT_SYMBOL_TABLE {
+            if shifted == c {
+                return (base, true);
+            }
+        }
+        (c, false)
+    }
+}
+
+/// Maximum number of alias hops [`Keyboard::convert_key`] follows before
+/// giving up, guarding against a cyclical alias table (e.g. `"a" -> "b"`,
+/// `"b" -> "a"`).
+const MAX_ALIAS_DEPTH: usize = 8;
+
 impl Keyboard {
     /// Create a new Keyboard instance.
     /// 
@@ -154,7 +301,82 @@ impl Keyboard {
                     format!("Failed to create Enigo instance: {}", e)
                 )
             ))?;
-        Ok(Keyboard { enigo })
+        Ok(Keyboard {
+            enigo,
+            held_keys: std::collections::HashSet::new(),
+            repeat_settings: RepeatSettings::default(),
+            layout: KeyboardLayout::Qwerty,
+            aliases: std::collections::HashMap::new(),
+        })
+    }
+
+    /// Sets a user-defined key alias table, e.g. loaded from a TOML/KDL
+    /// config mapping custom names to canonical ones (`"accept" = "enter"`,
+    /// `"cmd" = "meta"`). See [`Keyboard::convert_key`] for how aliases are
+    /// resolved.
+    pub fn with_aliases(mut self, aliases: std::collections::HashMap<String, String>) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// Resolves a key name to its `enigo` key code.
+    ///
+    /// The token is first looked up in the user alias table (see
+    /// [`Keyboard::with_aliases`]), recursively - an alias may point to
+    /// another alias - up to [`MAX_ALIAS_DEPTH`] hops, with cycle detection
+    /// so a table like `"a" -> "b", "b" -> "a"` errors instead of looping
+    /// forever. Once resolution bottoms out at a name with no further
+    /// alias, it falls through to the built-in key table.
+    fn convert_key(&self, key: &str) -> MoverResult<enigo::Key> {
+        let mut current = key.to_lowercase();
+        let mut seen = std::collections::HashSet::new();
+        seen.insert(current.clone());
+
+        for _ in 0..MAX_ALIAS_DEPTH {
+            let Some(next) = self.aliases.get(&current) else {
+                return convert_key_builtin(&current);
+            };
+            let next = next.to_lowercase();
+            if !seen.insert(next.clone()) {
+                return Err(mover_core::MoverError::Other(format!(
+                    "cyclical key alias detected while resolving '{}'", key
+                )));
+            }
+            current = next;
+        }
+
+        Err(mover_core::MoverError::Other(format!(
+            "key alias '{}' did not resolve within {} hops", key, MAX_ALIAS_DEPTH
+        )))
+    }
+
+    /// Sets the default [`RepeatSettings`] used by [`Keyboard::hold_key_default`].
+    pub fn with_repeat_settings(mut self, settings: RepeatSettings) -> Self {
+        self.repeat_settings = settings;
+        self
+    }
+
+    /// Sets the [`KeyboardLayout`] the target machine is configured with.
+    ///
+    /// Once set, `type_string`/`type_string_with_interval` route each logical
+    /// character through the layout's inverse mapping, so e.g. typing
+    /// `"hello"` under [`KeyboardLayout::Dvorak`] presses the physical keys
+    /// that yield `hello` on a Dvorak-configured remote machine.
+    ///
+    /// `press_key_typed`/`release_key_typed`/`tap_key_typed` (and
+    /// `press_key`/`release_key`) remap a `Key::Char` the same way. Named keys
+    /// are sent unchanged, including the letter and digit variants such as
+    /// `Key::A` that a name like `"a"` parses to, and the string-based
+    /// `tap_key`, `press_keys` and `press_hotkey` do no remapping.
+    pub fn with_layout(mut self, layout: KeyboardLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Maps a logical character to the physical character that must be
+    /// pressed to produce it under the active [`KeyboardLayout`].
+    fn remap_char(&self, c: char) -> char {
+        self.layout.physical_char(c)
     }
 
     /// Types a string of text instantly.
@@ -193,7 +415,17 @@ impl Keyboard {
     /// 
     /// This method is optimized for speed and will type text as fast as possible.
     /// For slower, more human-like typing, use `type_string_with_interval`.
+    ///
+    /// # Layout Remapping
+    ///
+    /// When a non-identity [`KeyboardLayout`] is active (see
+    /// [`Keyboard::with_layout`]), the fast `enigo.text()` path is bypassed
+    /// in favor of remapping and sending each character individually.
     pub fn type_string(&mut self, text: &str) -> MoverResult<()> {
+        if !self.layout.is_identity() {
+            return self.type_string_with_interval(text, 0.0);
+        }
+
         // Use the text method for faster typing
         self.enigo.text(text)
             .map_err(|e| mover_core::MoverError::PlatformError(
@@ -249,6 +481,7 @@ impl Keyboard {
     pub fn type_string_with_interval(&mut self, text: &str, interval: f64) -> MoverResult<()> {
         // For interval typing, we need to type character by character
         for c in text.chars() {
+            let c = self.remap_char(c);
             self.enigo.key(enigo::Key::Unicode(c), Direction::Click)
                 .map_err(|e| mover_core::MoverError::PlatformError(
                     mover_core::PlatformError::UnsupportedOperation(
@@ -262,7 +495,67 @@ impl Keyboard {
         
         Ok(())
     }
-    
+
+    /// Types a string using an [`InverseKeymap`] of the active
+    /// [`KeyboardLayout`] to decide, per character, which physical key to
+    /// tap and whether Shift must be held - rather than relying on the
+    /// backend/OS to infer shift state from a bare `Key::Unicode`.
+    ///
+    /// Runs of characters that share the same shift state are sent under a
+    /// single held Shift block instead of toggling Shift per character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mover_keyboard::Keyboard;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut keyboard = Keyboard::new()?;
+    ///     keyboard.type_string_shifted("Hello, World! (42%)", 0.0)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn type_string_shifted(&mut self, text: &str, interval: f64) -> MoverResult<()> {
+        let keymap = InverseKeymap::new(self.layout.clone());
+        let mut shift_held = false;
+
+        for c in text.chars() {
+            let (physical, needs_shift) = keymap.lookup(c);
+            if needs_shift != shift_held {
+                let direction = if needs_shift { Direction::Press } else { Direction::Release };
+                self.enigo.key(enigo::Key::Shift, direction)
+                    .map_err(|e| mover_core::MoverError::PlatformError(
+                        mover_core::PlatformError::UnsupportedOperation(
+                            format!("Failed to toggle shift: {}", e)
+                        )
+                    ))?;
+                shift_held = needs_shift;
+            }
+
+            self.enigo.key(enigo::Key::Unicode(physical), Direction::Click)
+                .map_err(|e| mover_core::MoverError::PlatformError(
+                    mover_core::PlatformError::UnsupportedOperation(
+                        format!("Failed to type character '{}': {}", c, e)
+                    )
+                ))?;
+
+            if interval > 0.0 {
+                thread::sleep(Duration::from_secs_f64(interval));
+            }
+        }
+
+        if shift_held {
+            self.enigo.key(enigo::Key::Shift, Direction::Release)
+                .map_err(|e| mover_core::MoverError::PlatformError(
+                    mover_core::PlatformError::UnsupportedOperation(
+                        format!("Failed to release shift: {}", e)
+                    )
+                ))?;
+        }
+
+        Ok(())
+    }
+
     /// Presses a key down (but does not release it).
     /// 
     /// This method is useful for holding down modifier keys or creating
@@ -305,13 +598,26 @@ impl Keyboard {
     /// - Some applications may not respond well to held-down keys
     /// - Use `tap_key` for simple press-and-release operations
     pub fn press_key(&mut self, key: &str) -> MoverResult<()> {
-        let key_code = convert_key(key)?;
+        self.press_key_typed(key.parse()?)
+    }
+
+    /// Presses a key down (but does not release it), given as a typed
+    /// [`Key`] rather than a `&str` name.
+    ///
+    /// See [`Keyboard::press_key`] for the string-based equivalent.
+    pub fn press_key_typed(&mut self, key: Key) -> MoverResult<()> {
+        let key = match key {
+            Key::Char(c) => Key::Char(self.remap_char(c)),
+            other => other,
+        };
+        let key_code = convert_typed_key(key)?;
         self.enigo.key(key_code, Direction::Press)
             .map_err(|e| mover_core::MoverError::PlatformError(
                 mover_core::PlatformError::UnsupportedOperation(
                     format!("Failed to press key: {}", e)
                 )
             ))?;
+        self.held_keys.insert(key);
         Ok(())
     }
     
@@ -357,13 +663,26 @@ impl Keyboard {
     /// - Unreleased keys can cause system-wide issues
     /// - Consider using `press_hotkey` for common combinations
     pub fn release_key(&mut self, key: &str) -> MoverResult<()> {
-        let key_code = convert_key(key)?;
+        self.release_key_typed(key.parse()?)
+    }
+
+    /// Releases a previously pressed key, given as a typed [`Key`] rather
+    /// than a `&str` name.
+    ///
+    /// See [`Keyboard::release_key`] for the string-based equivalent.
+    pub fn release_key_typed(&mut self, key: Key) -> MoverResult<()> {
+        let key = match key {
+            Key::Char(c) => Key::Char(self.remap_char(c)),
+            other => other,
+        };
+        let key_code = convert_typed_key(key)?;
         self.enigo.key(key_code, Direction::Release)
             .map_err(|e| mover_core::MoverError::PlatformError(
                 mover_core::PlatformError::UnsupportedOperation(
                     format!("Failed to release key: {}", e)
                 )
             ))?;
+        self.held_keys.remove(&key);
         Ok(())
     }
     
@@ -408,7 +727,26 @@ impl Keyboard {
     /// - Function keys: `"f1"`, `"f2"`, `"f3"`, etc.
     /// - Special keys: `"enter"`, `"space"`, `"tab"`, `"escape"`
     pub fn tap_key(&mut self, key: &str) -> MoverResult<()> {
-        let key_code = convert_key(key)?;
+        let key_code = self.convert_key(key)?;
+        self.enigo.key(key_code, Direction::Click)
+            .map_err(|e| mover_core::MoverError::PlatformError(
+                mover_core::PlatformError::UnsupportedOperation(
+                    format!("Failed to tap key: {}", e)
+                )
+            ))?;
+        Ok(())
+    }
+
+    /// Presses and immediately releases a key (tap), given as a typed
+    /// [`Key`] rather than a `&str` name.
+    ///
+    /// See [`Keyboard::tap_key`] for the string-based equivalent.
+    pub fn tap_key_typed(&mut self, key: Key) -> MoverResult<()> {
+        let key = match key {
+            Key::Char(c) => Key::Char(self.remap_char(c)),
+            other => other,
+        };
+        let key_code = convert_typed_key(key)?;
         self.enigo.key(key_code, Direction::Click)
             .map_err(|e| mover_core::MoverError::PlatformError(
                 mover_core::PlatformError::UnsupportedOperation(
@@ -458,7 +796,7 @@ impl Keyboard {
     /// - For faster typing, use `type_string` instead
     pub fn press_keys(&mut self, keys: &[&str]) -> MoverResult<()> {
         for key in keys {
-            let key_code = convert_key(key)?;
+            let key_code = self.convert_key(key)?;
             self.enigo.key(key_code, Direction::Click)
                 .map_err(|e| mover_core::MoverError::PlatformError(
                     mover_core::PlatformError::UnsupportedOperation(
@@ -529,7 +867,7 @@ impl Keyboard {
     pub fn press_hotkey(&mut self, keys: &[&str]) -> MoverResult<()> {
         // Press all keys down
         for key in keys {
-            let key_code = convert_key(key)?;
+            let key_code = self.convert_key(key)?;
             self.enigo.key(key_code, Direction::Press)
                 .map_err(|e| mover_core::MoverError::PlatformError(
                     mover_core::PlatformError::UnsupportedOperation(
@@ -543,7 +881,7 @@ impl Keyboard {
         
         // Release all keys up (in reverse order for some combinations)
         for key in keys.iter().rev() {
-            let key_code = convert_key(key)?;
+            let key_code = self.convert_key(key)?;
             self.enigo.key(key_code, Direction::Release)
                 .map_err(|e| mover_core::MoverError::PlatformError(
                     mover_core::PlatformError::UnsupportedOperation(
@@ -555,6 +893,117 @@ impl Keyboard {
         Ok(())
     }
     
+    /// Sends a hotkey combination given as a single `+`-separated string.
+    ///
+    /// This is a convenience wrapper around [`Keyboard::press_hotkey`] for callers
+    /// who have a combination like `"ctrl+shift+t"` as a string rather than an
+    /// array of key names.
+    ///
+    /// # Arguments
+    ///
+    /// * `combo` - The hotkey combination, e.g. `"ctrl+c"` or `"ctrl+shift+t"`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mover_keyboard::Keyboard;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut keyboard = Keyboard::new()?;
+    ///     keyboard.send_hotkey("ctrl+shift+t")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn send_hotkey(&mut self, combo: &str) -> MoverResult<()> {
+        let keys: Vec<&str> = combo.split('+').map(str::trim).collect();
+        self.press_hotkey(&keys)
+    }
+
+    /// Presses a key-combination ("chord") given as a single `+`-separated
+    /// string, e.g. `"ctrl+shift+a"` or `"cmd+alt+left"`.
+    ///
+    /// Unlike [`Keyboard::send_hotkey`], this validates the chord via
+    /// [`parse_chord`] before sending anything: every token but the last
+    /// must be a modifier key, and the modifiers are held down, the final
+    /// ("main") key is clicked, then the modifiers are released in reverse
+    /// order - guaranteed even if the click itself fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mover_keyboard::Keyboard;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut keyboard = Keyboard::new()?;
+    ///     keyboard.press_chord("ctrl+shift+a")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn press_chord(&mut self, spec: &str) -> MoverResult<()> {
+        let (modifiers, main) = parse_chord(spec)?;
+
+        let mut pressed = Vec::with_capacity(modifiers.len());
+        let mut press_result = Ok(());
+        for modifier in &modifiers {
+            match self.enigo.key(*modifier, Direction::Press) {
+                Ok(()) => pressed.push(*modifier),
+                Err(e) => {
+                    press_result = Err(mover_core::MoverError::PlatformError(
+                        mover_core::PlatformError::UnsupportedOperation(
+                            format!("Failed to press chord modifier: {}", e)
+                        )
+                    ));
+                    break;
+                }
+            }
+        }
+
+        let result = press_result.and_then(|()| {
+            self.enigo.key(main, Direction::Click)
+                .map_err(|e| mover_core::MoverError::PlatformError(
+                    mover_core::PlatformError::UnsupportedOperation(
+                        format!("Failed to click chord main key: {}", e)
+                    )
+                ))
+        });
+
+        for modifier in pressed.into_iter().rev() {
+            let _ = self.enigo.key(modifier, Direction::Release);
+        }
+
+        result
+    }
+
+    /// Presses a sequence of chords in order, Emacs-style (e.g. `Ctrl+X` then
+    /// `Ctrl+S` to save). Each inner slice is one chord's key names, with
+    /// the last element as the main key and the rest as modifiers - the same
+    /// rules as [`Keyboard::press_chord`], which presses each step.
+    ///
+    /// If any step fails, the sequence stops and the error is returned; the
+    /// failed step's own modifiers are still released, since that guarantee
+    /// already lives in [`Keyboard::press_chord`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mover_keyboard::Keyboard;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut keyboard = Keyboard::new()?;
+    ///     // Emacs "save as": Ctrl+X, then Ctrl+W
+    ///     keyboard.press_chord_sequence(&[&["ctrl", "x"], &["ctrl", "w"]])?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn press_chord_sequence(&mut self, chords: &[&[&str]]) -> MoverResult<()> {
+        for chord in chords {
+            let spec = chord.join("+");
+            self.press_chord(&spec)?;
+            thread::sleep(Duration::from_millis(50));
+        }
+        Ok(())
+    }
+
     /// Presses a key multiple times with specified intervals.
     /// 
     /// This method is useful for repeating actions like pressing arrow keys
@@ -599,7 +1048,7 @@ impl Keyboard {
     /// - **Patterns**: Create rhythmic keyboard patterns
     /// - **Testing**: Verify repeated key functionality
     pub fn press_key_multiple(&mut self, key: &str, times: u32, interval: f64) -> MoverResult<()> {
-        let key_code = convert_key(key)?;
+        let key_code = self.convert_key(key)?;
         
         for _ in 0..times {
             self.enigo.key(key_code, Direction::Click)
@@ -615,9 +1064,299 @@ impl Keyboard {
         
         Ok(())
     }
-    
+
+    /// Holds `key` down for `duration` seconds, reproducing native
+    /// key-repetition: the key is pressed, held silently for
+    /// `repeat_delay` seconds, then re-clicked every `1.0 / repeat_rate`
+    /// seconds until `duration` has elapsed, and finally released.
+    ///
+    /// This is the timed-repeat counterpart to [`Keyboard::press_key_multiple`],
+    /// which only emits taps at a fixed interval with no initial delay.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mover_keyboard::Keyboard;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut keyboard = Keyboard::new()?;
+    ///     // Hold the down-arrow for one second, repeating after 0.5s at 20Hz
+    ///     keyboard.hold_key("down", 1.0, 0.5, 20.0)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn hold_key(
+        &mut self,
+        key: &str,
+        duration: f64,
+        repeat_delay: f64,
+        repeat_rate: f64,
+    ) -> MoverResult<()> {
+        let key_code = self.convert_key(key)?;
+        self.enigo.key(key_code, Direction::Press)
+            .map_err(|e| mover_core::MoverError::PlatformError(
+                mover_core::PlatformError::UnsupportedOperation(
+                    format!("Failed to press key: {}", e)
+                )
+            ))?;
+
+        let total = Duration::from_secs_f64(duration.max(0.0));
+        let start = std::time::Instant::now();
+
+        let initial_wait = Duration::from_secs_f64(repeat_delay.max(0.0)).min(total);
+        thread::sleep(initial_wait);
+
+        if repeat_rate > 0.0 {
+            let interval = Duration::from_secs_f64(1.0 / repeat_rate);
+            while start.elapsed() < total {
+                self.enigo.key(key_code, Direction::Click)
+                    .map_err(|e| mover_core::MoverError::PlatformError(
+                        mover_core::PlatformError::UnsupportedOperation(
+                            format!("Failed to repeat key: {}", e)
+                        )
+                    ))?;
+                let remaining = total.saturating_sub(start.elapsed());
+                thread::sleep(interval.min(remaining));
+            }
+        } else {
+            let remaining = total.saturating_sub(start.elapsed());
+            thread::sleep(remaining);
+        }
+
+        self.enigo.key(key_code, Direction::Release)
+            .map_err(|e| mover_core::MoverError::PlatformError(
+                mover_core::PlatformError::UnsupportedOperation(
+                    format!("Failed to release key: {}", e)
+                )
+            ))?;
+        Ok(())
+    }
+
+    /// Like [`Keyboard::hold_key`], but using this `Keyboard`'s default
+    /// [`RepeatSettings`] (see [`Keyboard::with_repeat_settings`]) instead of
+    /// explicit delay/rate arguments.
+    pub fn hold_key_default(&mut self, key: &str, duration: f64) -> MoverResult<()> {
+        let settings = self.repeat_settings;
+        self.hold_key(key, duration, settings.delay, settings.rate)
+    }
+
+    /// Parses and executes a compact keystroke spec, borrowing its grammar
+    /// from Dragonfly's `Key` action so multi-step macros can be written as a
+    /// single declarative string instead of chained `press_key`/`release_key`
+    /// calls.
+    ///
+    /// A spec is a comma-separated list of elements. Each element is an
+    /// optional `/timing` pause after a `name[:count]` or `name:down`/`:up`/`:press`
+    /// key, where `name` itself may be a `+`-separated modifier chord:
+    ///
+    /// - `"ctrl+c"` - a chord, pressed via [`Keyboard::press_hotkey`]
+    /// - `"a:3"` - taps `a` three times
+    /// - `"escape/0.1"` - taps escape, then pauses 0.1s
+    /// - `"shift:down, left:5, shift:up"` - holds shift, taps left 5 times, releases shift
+    ///
+    /// # Errors
+    ///
+    /// Returns a `MoverError::Other` naming the offending token if a token
+    /// can't be parsed (e.g. a non-numeric repeat count or an empty chord
+    /// part), or whatever error the underlying key operation produces.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mover_keyboard::Keyboard;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut keyboard = Keyboard::new()?;
+    ///     keyboard.send_keys("ctrl+c, a:3, escape/0.1, shift:down, left:5, shift:up")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn send_keys(&mut self, spec: &str) -> MoverResult<()> {
+        for token in spec.split(',') {
+            let token = token.trim();
+            if token.is_empty() {
+                continue;
+            }
+            self.send_key_token(token)?;
+        }
+        Ok(())
+    }
+
+    /// Parses and executes a single `send_keys` element (everything between
+    /// commas).
+    fn send_key_token(&mut self, token: &str) -> MoverResult<()> {
+        let (head, timing) = match token.split_once('/') {
+            Some((head, timing)) => (head.trim(), Some(timing.trim())),
+            None => (token, None),
+        };
+
+        let (name_part, count, direction) = match head.rsplit_once(':') {
+            Some((name, "down")) => (name, 1, Direction::Press),
+            Some((name, "up")) => (name, 1, Direction::Release),
+            Some((name, "press")) => (name, 1, Direction::Click),
+            Some((name, suffix)) => {
+                let count: u32 = suffix.parse().map_err(|_| keystroke_spec_error(token))?;
+                (name, count, Direction::Click)
+            }
+            None => (head, 1, Direction::Click),
+        };
+
+        let keys: Vec<&str> = name_part.split('+').map(str::trim).collect();
+        if name_part.is_empty() || keys.iter().any(|key| key.is_empty()) {
+            return Err(keystroke_spec_error(token));
+        }
+
+        match direction {
+            Direction::Press => {
+                for key in &keys {
+                    self.press_key(key)?;
+                }
+            }
+            Direction::Release => {
+                for key in &keys {
+                    self.release_key(key)?;
+                }
+            }
+            Direction::Click => {
+                for _ in 0..count.max(1) {
+                    if keys.len() > 1 {
+                        self.press_hotkey(&keys)?;
+                    } else {
+                        self.tap_key(keys[0])?;
+                    }
+                }
+            }
+        }
+
+        if let Some(timing) = timing {
+            let seconds: f64 = timing.parse().map_err(|_| keystroke_spec_error(token))?;
+            self.sleep(seconds);
+        }
+
+        Ok(())
+    }
+
+    /// Taps a media / consumer-control button - volume, playback, and
+    /// similar system keys that sit outside the normal typing keyboard.
+    ///
+    /// Not every `enigo` backend exposes every media key; buttons without a
+    /// mapping on the current platform return a clear `UnsupportedOperation`
+    /// error rather than silently doing nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mover_keyboard::Keyboard;
+    /// use mover_core::MediaButton;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut keyboard = Keyboard::new()?;
+    ///     keyboard.media_button(MediaButton::VolumeUp)?;
+    ///     keyboard.media_button(MediaButton::MediaPlayPause)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn media_button(&mut self, button: MediaButton) -> MoverResult<()> {
+        let key_code = convert_media_button(button)?;
+        self.enigo.key(key_code, Direction::Click)
+            .map_err(|e| mover_core::MoverError::PlatformError(
+                mover_core::PlatformError::UnsupportedOperation(
+                    format!("Failed to tap media button: {}", e)
+                )
+            ))?;
+        Ok(())
+    }
+
+    /// Returns whether `key` is currently held down, as tracked by
+    /// `press_key`/`release_key`.
+    pub fn is_key_down(&self, key: Key) -> bool {
+        self.held_keys.contains(&key)
+    }
+
+    /// Returns every key currently held down, as tracked by
+    /// `press_key`/`release_key`.
+    pub fn pressed_keys(&self) -> Vec<Key> {
+        self.held_keys.iter().copied().collect()
+    }
+
+    /// Returns the bitset of modifier keys (ctrl/shift/alt/meta) currently
+    /// held, derived from the same `held_keys` tracking as
+    /// [`Keyboard::is_key_down`], which records `press_key`/`release_key` and
+    /// their typed variants. [`Keyboard::press_chord`],
+    /// [`Keyboard::press_chord_sequence`] and `press_hotkey` press and release
+    /// their modifiers without updating it.
+    pub fn modifier_state(&self) -> mover_core::Modifiers {
+        let mut modifiers = mover_core::Modifiers::NONE;
+        if self.held_keys.contains(&Key::Control) {
+            modifiers = modifiers.union(mover_core::Modifiers::CTRL);
+        }
+        if self.held_keys.contains(&Key::Shift) {
+            modifiers = modifiers.union(mover_core::Modifiers::SHIFT);
+        }
+        if self.held_keys.contains(&Key::Alt) {
+            modifiers = modifiers.union(mover_core::Modifiers::ALT);
+        }
+        if self.held_keys.contains(&Key::Meta) {
+            modifiers = modifiers.union(mover_core::Modifiers::META);
+        }
+        modifiers
+    }
+
+    /// Releases every key currently tracked as held down. Safe to call even
+    /// when nothing is held. Automatically run on `Drop`, but exposed so
+    /// scripts can recover from a stuck key without dropping the `Keyboard`.
+    pub fn release_all(&mut self) -> MoverResult<()> {
+        for key in self.pressed_keys() {
+            self.release_key_typed(key)?;
+        }
+        Ok(())
+    }
+
+    /// Runs `f` with the given keys held down, guaranteeing they are
+    /// released afterward even if `f` returns an error.
+    ///
+    /// The keys are pressed in order, `f` runs, then the keys are released
+    /// in reverse order - the safe way to run a block under held modifiers
+    /// without risking a stuck key if `f` errors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mover_keyboard::Keyboard;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut keyboard = Keyboard::new()?;
+    ///     keyboard.with_held(&["ctrl"], |kb| kb.tap_key("c"))?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_held<F>(&mut self, keys: &[&str], f: F) -> MoverResult<()>
+    where
+        F: FnOnce(&mut Self) -> MoverResult<()>,
+    {
+        let mut pressed = Vec::with_capacity(keys.len());
+        let mut press_result = Ok(());
+        for key in keys {
+            match self.press_key(key) {
+                Ok(()) => pressed.push(*key),
+                Err(e) => {
+                    press_result = Err(e);
+                    break;
+                }
+            }
+        }
+
+        let result = press_result.and_then(|()| f(self));
+
+        for key in pressed.into_iter().rev() {
+            let _ = self.release_key(key);
+        }
+
+        result
+    }
+
     /// Sleep for a given number of seconds.
-    /// 
+    ///
     /// This utility function pauses execution for the specified duration.
     /// Useful for creating delays in automation scripts or waiting
     /// for applications to respond.
@@ -666,6 +1405,15 @@ impl Keyboard {
     }
 }
 
+impl Drop for Keyboard {
+    /// Releases any key still tracked as held, so a panic or early `?`
+    /// return between a press and its matching release can't leave a
+    /// physical key stuck down.
+    fn drop(&mut self) {
+        let _ = self.release_all();
+    }
+}
+
 /// Convert string key names to enigo Key codes.
 /// 
 /// This internal function maps human-readable key names to the `enigo` crate's
@@ -706,72 +1454,321 @@ impl Keyboard {
 /// 
 /// Returns an error if the key name is not recognized or supported.
 /// The error message will indicate which key was not supported.
-fn convert_key(key: &str) -> MoverResult<enigo::Key> {
-    let key_lower = key.to_lowercase();
-    
-    match key_lower.as_str() {
-        // Basic keys
-        "a" | "b" | "c" | "d" | "e" | "f" | "g" | "h" | "i" | "j" | "k" | "l" | "m" |
-        "n" | "o" | "p" | "q" | "r" | "s" | "t" | "u" | "v" | "w" | "x" | "y" | "z" => {
-            Ok(enigo::Key::Unicode(key_lower.chars().next().unwrap()))
+fn convert_key_builtin(key: &str) -> MoverResult<enigo::Key> {
+    if let Ok(key) = key.parse::<Key>() {
+        return convert_typed_key(key);
+    }
+    // Not a Key name - fall back to the media/system-control vocabulary so
+    // `press_key`/`tap_key` can also drive volume and playback keys, not
+    // just letters and navigation.
+    let button: MediaButton = key.parse()?;
+    convert_media_button(button)
+}
+
+/// Parses a `+`-separated key-combination ("chord") spec like
+/// `"ctrl+shift+a"` into its modifier keys (in press order) and final main
+/// key, case-insensitively.
+///
+/// Every token but the last must resolve to a modifier key (`Key::Control`,
+/// `Key::Shift`, `Key::Alt`, or `Key::Meta`); a non-modifier appearing
+/// before the last token is rejected as a chord with two main keys. A
+/// trailing `+`-separated empty token (e.g. `"ctrl+"`) is rejected, except
+/// that a literal `+` main key is still reachable via a doubled trailing
+/// `+` (e.g. `"ctrl++"` means "Ctrl, plus the `+` key").
+pub fn parse_chord(spec: &str) -> MoverResult<(Vec<enigo::Key>, enigo::Key)> {
+    let spec = spec.trim();
+    if spec.is_empty() {
+        return Err(mover_core::MoverError::Other("empty key chord".to_string()));
+    }
+
+    let mut tokens: Vec<&str> = spec.split('+').collect();
+    if tokens.len() >= 2
+        && tokens[tokens.len() - 1].is_empty()
+        && tokens[tokens.len() - 2].is_empty()
+    {
+        tokens.pop();
+        tokens.pop();
+        tokens.push("+");
+    }
+
+    if tokens.iter().any(|token| token.is_empty()) {
+        return Err(mover_core::MoverError::Other(
+            format!("invalid key chord '{}': empty key token", spec)
+        ));
+    }
+
+    let (modifier_tokens, main_token) = tokens.split_at(tokens.len() - 1);
+    let main_token = main_token[0];
+
+    let mut modifiers = Vec::with_capacity(modifier_tokens.len());
+    for token in modifier_tokens {
+        let key: Key = token.parse()?;
+        if !matches!(key, Key::Control | Key::Shift | Key::Alt | Key::Meta) {
+            return Err(mover_core::MoverError::Other(format!(
+                "invalid key chord '{}': '{}' is not a modifier key",
+                spec, token
+            )));
+        }
+        modifiers.push(key);
+    }
+
+    let main_key: Key = main_token.parse()?;
+    let (modifiers, main_key) = canonicalize_shift(modifiers, main_key, spec)?;
+
+    let modifier_codes = modifiers.into_iter().map(convert_typed_key).collect::<MoverResult<Vec<_>>>()?;
+    let main_code = convert_typed_key(main_key)?;
+
+    Ok((modifier_codes, main_code))
+}
+
+/// Folds a single-letter or single-digit named [`Key`] variant (`Key::A`,
+/// `Key::Key1`, ...) to the character it types, so callers that work on
+/// `Key::Char` don't also need to special-case the named variants that
+/// `Key::from_str` produces for plain letters and digits.
+fn key_to_char(key: &Key) -> Option<char> {
+    use Key::*;
+
+    Some(match key {
+        Char(c) => *c,
+        A => 'a', B => 'b', C => 'c', D => 'd', E => 'e', F => 'f', G => 'g',
+        H => 'h', I => 'i', J => 'j', K => 'k', L => 'l', M => 'm', N => 'n',
+        O => 'o', P => 'p', Q => 'q', R => 'r', S => 's', T => 't', U => 'u',
+        V => 'v', W => 'w', X => 'x', Y => 'y', Z => 'z',
+        Key0 => '0', Key1 => '1', Key2 => '2', Key3 => '3', Key4 => '4',
+        Key5 => '5', Key6 => '6', Key7 => '7', Key8 => '8', Key9 => '9',
+        _ => return None,
+    })
+}
+
+/// Canonicalizes a `Shift` modifier combined with a printable character,
+/// following the rule platforms like kakoune and git-interactive-rebase-tool
+/// use to avoid double-shift artifacts: `shift+a` becomes the uppercase `A`
+/// with `Shift` dropped, and `shift+1` resolves to the shifted symbol `!` on
+/// a US layout. `Shift` combined with a special key (arrows, Home, Tab,
+/// function keys, ...) is left alone and sent as a real modifier, which is
+/// what makes `shift+tab` work as a "backtab" without a dedicated key name.
+fn canonicalize_shift(modifiers: Vec<Key>, main: Key, spec: &str) -> MoverResult<(Vec<Key>, Key)> {
+    let Some(shift_index) = modifiers.iter().position(|m| *m == Key::Shift) else {
+        return Ok((modifiers, main));
+    };
+
+    // `Key::from_str` resolves single letters/digits to their named `Key::A`..`Key::Z`/
+    // `Key::Key0`..`Key::Key9` variants rather than `Key::Char`, so those need folding to
+    // their character form before the printable-character logic below can see them.
+    let Some(c) = key_to_char(&main) else {
+        // A non-printable special key: keep Shift as a real modifier.
+        return Ok((modifiers, main));
+    };
+
+    if c.is_ascii_lowercase() {
+        let mut modifiers = modifiers;
+        modifiers.remove(shift_index);
+        return Ok((modifiers, Key::Char(c.to_ascii_uppercase())));
+    }
+
+    if !c.is_ascii_uppercase() && !c.is_ascii_alphabetic() {
+        if let Some(&(shifted, _)) = SHIFT_SYMBOL_TABLE.iter().find(|&&(_, base)| base == c) {
+            let mut modifiers = modifiers;
+            modifiers.remove(shift_index);
+            return Ok((modifiers, Key::Char(shifted)));
         }
-        "0" | "1" | "2" | "3" | "4" | "5" | "6" | "7" | "8" | "9" => {
-            Ok(enigo::Key::Unicode(key_lower.chars().next().unwrap()))
+    }
+
+    Err(mover_core::MoverError::PlatformError(
+        mover_core::PlatformError::UnsupportedOperation(format!(
+            "invalid key chord '{}': Shift only combines with special keys or lowercase ASCII, not '{}'",
+            spec, c
+        ))
+    ))
+}
+
+/// Converts a typed [`Key`] to its `enigo` counterpart.
+///
+/// `enigo` has no dedicated numpad scancodes distinct from the character a
+/// press produces, so the numpad operator keys fall back to the `Unicode`
+/// variant of the character they normally type (`NumpadAdd` -> `'+'`, etc),
+/// and `NumpadEnter` falls back to the same `Return` as the main Enter key.
+fn convert_typed_key(key: Key) -> MoverResult<enigo::Key> {
+    use Key::*;
+
+    Ok(match key {
+        A => enigo::Key::Unicode('a'), B => enigo::Key::Unicode('b'),
+        C => enigo::Key::Unicode('c'), D => enigo::Key::Unicode('d'),
+        E => enigo::Key::Unicode('e'), F => enigo::Key::Unicode('f'),
+        G => enigo::Key::Unicode('g'), H => enigo::Key::Unicode('h'),
+        I => enigo::Key::Unicode('i'), J => enigo::Key::Unicode('j'),
+        K => enigo::Key::Unicode('k'), L => enigo::Key::Unicode('l'),
+        M => enigo::Key::Unicode('m'), N => enigo::Key::Unicode('n'),
+        O => enigo::Key::Unicode('o'), P => enigo::Key::Unicode('p'),
+        Q => enigo::Key::Unicode('q'), R => enigo::Key::Unicode('r'),
+        S => enigo::Key::Unicode('s'), T => enigo::Key::Unicode('t'),
+        U => enigo::Key::Unicode('u'), V => enigo::Key::Unicode('v'),
+        W => enigo::Key::Unicode('w'), X => enigo::Key::Unicode('x'),
+        Y => enigo::Key::Unicode('y'), Z => enigo::Key::Unicode('z'),
+
+        Key0 => enigo::Key::Unicode('0'), Key1 => enigo::Key::Unicode('1'),
+        Key2 => enigo::Key::Unicode('2'), Key3 => enigo::Key::Unicode('3'),
+        Key4 => enigo::Key::Unicode('4'), Key5 => enigo::Key::Unicode('5'),
+        Key6 => enigo::Key::Unicode('6'), Key7 => enigo::Key::Unicode('7'),
+        Key8 => enigo::Key::Unicode('8'), Key9 => enigo::Key::Unicode('9'),
+
+        F1 => enigo::Key::F1, F2 => enigo::Key::F2, F3 => enigo::Key::F3, F4 => enigo::Key::F4,
+        F5 => enigo::Key::F5, F6 => enigo::Key::F6, F7 => enigo::Key::F7, F8 => enigo::Key::F8,
+        F9 => enigo::Key::F9, F10 => enigo::Key::F10, F11 => enigo::Key::F11, F12 => enigo::Key::F12,
+        F13 | F14 | F15 | F16 | F17 | F18 | F19 | F20 | F21 | F22 | F23 | F24 => {
+            return Err(mover_core::MoverError::PlatformError(
+                mover_core::PlatformError::UnsupportedOperation(format!("{:?} has no enigo mapping", key)),
+            ))
         }
-        
-        // Special keys
-        "enter" | "return" => Ok(enigo::Key::Return),
-        "space" => Ok(enigo::Key::Space),
-        "tab" => Ok(enigo::Key::Tab),
-        "escape" | "esc" => Ok(enigo::Key::Escape),
-        "backspace" => Ok(enigo::Key::Backspace),
-        "delete" | "del" => Ok(enigo::Key::Delete),
-        "home" => Ok(enigo::Key::Home),
-        "end" => Ok(enigo::Key::End),
-        "pageup" | "pgup" => Ok(enigo::Key::PageUp),
-        "pagedown" | "pgdn" => Ok(enigo::Key::PageDown),
-        
-        // Arrow keys
-        "up" => Ok(enigo::Key::UpArrow),
-        "down" => Ok(enigo::Key::DownArrow),
-        "left" => Ok(enigo::Key::LeftArrow),
-        "right" => Ok(enigo::Key::RightArrow),
-        
-        // Function keys
-        "f1" => Ok(enigo::Key::F1),
-        "f2" => Ok(enigo::Key::F2),
-        "f3" => Ok(enigo::Key::F3),
-        "f4" => Ok(enigo::Key::F4),
-        "f5" => Ok(enigo::Key::F5),
-        "f6" => Ok(enigo::Key::F6),
-        "f7" => Ok(enigo::Key::F7),
-        "f8" => Ok(enigo::Key::F8),
-        "f9" => Ok(enigo::Key::F9),
-        "f10" => Ok(enigo::Key::F10),
-        "f11" => Ok(enigo::Key::F11),
-        "f12" => Ok(enigo::Key::F12),
-        
-        // Modifier keys
-        "ctrl" | "control" => Ok(enigo::Key::Control),
-        "alt" => Ok(enigo::Key::Alt),
-        "shift" => Ok(enigo::Key::Shift),
-        "meta" | "win" | "command" => Ok(enigo::Key::Meta),
-        
-        // Punctuation and symbols
-        "!" | "@" | "#" | "$" | "%" | "^" | "&" | "*" | "(" | ")" | "-" | "_" | "=" | "+" |
-        "[" | "]" | "{" | "}" | "\\" | "|" | ";" | ":" | "'" | "\"" | "," | "." | "/" | "?" |
-        "`" | "~" => {
-            Ok(enigo::Key::Unicode(key_lower.chars().next().unwrap()))
+
+        Numpad0 => enigo::Key::Unicode('0'), Numpad1 => enigo::Key::Unicode('1'),
+        Numpad2 => enigo::Key::Unicode('2'), Numpad3 => enigo::Key::Unicode('3'),
+        Numpad4 => enigo::Key::Unicode('4'), Numpad5 => enigo::Key::Unicode('5'),
+        Numpad6 => enigo::Key::Unicode('6'), Numpad7 => enigo::Key::Unicode('7'),
+        Numpad8 => enigo::Key::Unicode('8'), Numpad9 => enigo::Key::Unicode('9'),
+        NumpadAdd => enigo::Key::Unicode('+'),
+        NumpadSubtract => enigo::Key::Unicode('-'),
+        NumpadMultiply => enigo::Key::Unicode('*'),
+        NumpadDivide => enigo::Key::Unicode('/'),
+        NumpadDecimal => enigo::Key::Unicode('.'),
+        NumpadEnter => enigo::Key::Return,
+
+        Insert => enigo::Key::Insert,
+        Home => enigo::Key::Home,
+        End => enigo::Key::End,
+        PageUp => enigo::Key::PageUp,
+        PageDown => enigo::Key::PageDown,
+
+        Snapshot => enigo::Key::Print,
+        Scroll => enigo::Key::ScrollLock,
+        Pause => enigo::Key::Pause,
+
+        Up => enigo::Key::UpArrow,
+        Down => enigo::Key::DownArrow,
+        Left => enigo::Key::LeftArrow,
+        Right => enigo::Key::RightArrow,
+
+        Return => enigo::Key::Return,
+        Escape => enigo::Key::Escape,
+        Tab => enigo::Key::Tab,
+        BackTab => {
+            return Err(mover_core::MoverError::PlatformError(
+                mover_core::PlatformError::UnsupportedOperation(
+                    "BackTab has no single-key enigo mapping - use press_chord(\"shift+tab\") instead".to_string(),
+                ),
+            ))
         }
-        
-        _ => Err(mover_core::MoverError::PlatformError(
+        Space => enigo::Key::Space,
+        Backspace => enigo::Key::Backspace,
+        Delete => enigo::Key::Delete,
+
+        Control => enigo::Key::Control,
+        Alt => enigo::Key::Alt,
+        Shift => enigo::Key::Shift,
+        Meta => enigo::Key::Meta,
+
+        Char(c) => enigo::Key::Unicode(c),
+    })
+}
+
+/// Describes an `enigo::Key` back to its canonical lowercase name, the
+/// reverse of [`Keyboard::convert_key`]/[`convert_typed_key`] - e.g. for logging,
+/// config round-tripping, or rendering a stored keybinding in a UI.
+///
+/// Covers every variant this crate's conversion functions can produce;
+/// anything else falls back to a lowercased `Debug` rendering.
+pub fn describe_key(key: enigo::Key) -> String {
+    match key {
+        enigo::Key::Unicode(c) => c.to_string(),
+
+        enigo::Key::Return => "enter".to_string(),
+        enigo::Key::Escape => "escape".to_string(),
+        enigo::Key::Tab => "tab".to_string(),
+        enigo::Key::Space => "space".to_string(),
+        enigo::Key::Backspace => "backspace".to_string(),
+        enigo::Key::Delete => "delete".to_string(),
+
+        enigo::Key::Insert => "insert".to_string(),
+        enigo::Key::Home => "home".to_string(),
+        enigo::Key::End => "end".to_string(),
+        enigo::Key::PageUp => "pageup".to_string(),
+        enigo::Key::PageDown => "pagedown".to_string(),
+
+        enigo::Key::Print => "snapshot".to_string(),
+        enigo::Key::ScrollLock => "scroll".to_string(),
+        enigo::Key::Pause => "pause".to_string(),
+
+        enigo::Key::UpArrow => "up".to_string(),
+        enigo::Key::DownArrow => "down".to_string(),
+        enigo::Key::LeftArrow => "left".to_string(),
+        enigo::Key::RightArrow => "right".to_string(),
+
+        enigo::Key::Control => "ctrl".to_string(),
+        enigo::Key::Alt => "alt".to_string(),
+        enigo::Key::Shift => "shift".to_string(),
+        enigo::Key::Meta => "meta".to_string(),
+
+        enigo::Key::F1 => "f1".to_string(), enigo::Key::F2 => "f2".to_string(),
+        enigo::Key::F3 => "f3".to_string(), enigo::Key::F4 => "f4".to_string(),
+        enigo::Key::F5 => "f5".to_string(), enigo::Key::F6 => "f6".to_string(),
+        enigo::Key::F7 => "f7".to_string(), enigo::Key::F8 => "f8".to_string(),
+        enigo::Key::F9 => "f9".to_string(), enigo::Key::F10 => "f10".to_string(),
+        enigo::Key::F11 => "f11".to_string(), enigo::Key::F12 => "f12".to_string(),
+
+        enigo::Key::VolumeUp => "volumeup".to_string(),
+        enigo::Key::VolumeDown => "volumedown".to_string(),
+        enigo::Key::VolumeMute => "mute".to_string(),
+        enigo::Key::MediaPlayPause => "mediaplaypause".to_string(),
+        enigo::Key::MediaNextTrack => "medianext".to_string(),
+        enigo::Key::MediaPrevTrack => "mediaprev".to_string(),
+
+        other => format!("{:?}", other).to_lowercase(),
+    }
+}
+
+/// Describes a parsed chord (as returned by [`parse_chord`]) back to its
+/// canonical `+`-separated string, in a stable modifier order
+/// (`ctrl, alt, shift, meta`) regardless of the order they were typed in.
+pub fn describe_chord(modifiers: &[enigo::Key], main: enigo::Key) -> String {
+    const ORDER: [enigo::Key; 4] = [
+        enigo::Key::Control,
+        enigo::Key::Alt,
+        enigo::Key::Shift,
+        enigo::Key::Meta,
+    ];
+
+    let mut parts: Vec<String> = ORDER
+        .into_iter()
+        .filter(|modifier| modifiers.contains(modifier))
+        .map(describe_key)
+        .collect();
+    parts.push(describe_key(main));
+    parts.join("+")
+}
+
+/// Converts a [`MediaButton`] to its `enigo` counterpart, if the current
+/// `enigo` backend exposes one.
+fn convert_media_button(button: MediaButton) -> MoverResult<enigo::Key> {
+    match button {
+        MediaButton::VolumeUp => Ok(enigo::Key::VolumeUp),
+        MediaButton::VolumeDown => Ok(enigo::Key::VolumeDown),
+        MediaButton::Mute => Ok(enigo::Key::VolumeMute),
+        MediaButton::MediaPlayPause => Ok(enigo::Key::MediaPlayPause),
+        MediaButton::MediaNext => Ok(enigo::Key::MediaNextTrack),
+        MediaButton::MediaPrev => Ok(enigo::Key::MediaPrevTrack),
+        MediaButton::MediaStop => Err(mover_core::MoverError::PlatformError(
             mover_core::PlatformError::UnsupportedOperation(
-                format!("Unsupported key: {}", key)
-            )
-        ))
+                "MediaStop has no enigo mapping on this platform".to_string(),
+            ),
+        )),
     }
 }
 
+/// Builds the error returned when a `send_keys` token can't be parsed.
+fn keystroke_spec_error(token: &str) -> mover_core::MoverError {
+    mover_core::MoverError::Other(format!("invalid keystroke spec token: '{}'", token))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -785,57 +1782,153 @@ mod tests {
     #[test]
     fn test_convert_key_basic() {
         // Test basic keys
-        assert!(convert_key("a").is_ok());
-        assert!(convert_key("1").is_ok());
-        assert!(convert_key("!").is_ok());
+        assert!(convert_key_builtin("a").is_ok());
+        assert!(convert_key_builtin("1").is_ok());
+        assert!(convert_key_builtin("!").is_ok());
     }
 
     #[test]
     fn test_convert_key_special() {
         // Test special keys
-        assert!(convert_key("enter").is_ok());
-        assert!(convert_key("space").is_ok());
-        assert!(convert_key("tab").is_ok());
-        assert!(convert_key("escape").is_ok());
+        assert!(convert_key_builtin("enter").is_ok());
+        assert!(convert_key_builtin("space").is_ok());
+        assert!(convert_key_builtin("tab").is_ok());
+        assert!(convert_key_builtin("escape").is_ok());
     }
 
     #[test]
     fn test_convert_key_function() {
         // Test function keys
-        assert!(convert_key("f1").is_ok());
-        assert!(convert_key("f12").is_ok());
+        assert!(convert_key_builtin("f1").is_ok());
+        assert!(convert_key_builtin("f12").is_ok());
     }
 
     #[test]
     fn test_convert_key_modifier() {
         // Test modifier keys
-        assert!(convert_key("ctrl").is_ok());
-        assert!(convert_key("shift").is_ok());
-        assert!(convert_key("alt").is_ok());
-        assert!(convert_key("meta").is_ok());
+        assert!(convert_key_builtin("ctrl").is_ok());
+        assert!(convert_key_builtin("shift").is_ok());
+        assert!(convert_key_builtin("alt").is_ok());
+        assert!(convert_key_builtin("meta").is_ok());
     }
 
     #[test]
     fn test_convert_key_navigation() {
         // Test navigation keys
-        assert!(convert_key("up").is_ok());
-        assert!(convert_key("down").is_ok());
-        assert!(convert_key("left").is_ok());
-        assert!(convert_key("right").is_ok());
+        assert!(convert_key_builtin("up").is_ok());
+        assert!(convert_key_builtin("down").is_ok());
+        assert!(convert_key_builtin("left").is_ok());
+        assert!(convert_key_builtin("right").is_ok());
     }
 
     #[test]
     fn test_convert_key_case_insensitive() {
         // Test case insensitivity
-        assert!(convert_key("ENTER").is_ok());
-        assert!(convert_key("Enter").is_ok());
-        assert!(convert_key("enter").is_ok());
+        assert!(convert_key_builtin("ENTER").is_ok());
+        assert!(convert_key_builtin("Enter").is_ok());
+        assert!(convert_key_builtin("enter").is_ok());
     }
 
     #[test]
     fn test_convert_key_unsupported() {
         // Test unsupported keys
-        assert!(convert_key("unsupported_key").is_err());
-        assert!(convert_key("").is_err());
+        assert!(convert_key_builtin("unsupported_key").is_err());
+        assert!(convert_key_builtin("").is_err());
+    }
+
+    #[test]
+    fn test_describe_key_basic() {
+        assert_eq!(describe_key(convert_key_builtin("enter").unwrap()), "enter");
+        assert_eq!(describe_key(convert_key_builtin("up").unwrap()), "up");
+        assert_eq!(describe_key(convert_key_builtin("f5").unwrap()), "f5");
+        assert_eq!(describe_key(convert_key_builtin("ctrl").unwrap()), "ctrl");
+        assert_eq!(describe_key(convert_key_builtin("a").unwrap()), "a");
+    }
+
+    #[test]
+    fn test_describe_chord_stable_modifier_order() {
+        let (modifiers, main) = parse_chord("meta+ctrl+end").unwrap();
+        assert_eq!(describe_chord(&modifiers, main), "ctrl+meta+end");
+    }
+
+    #[test]
+    fn test_describe_chord_round_trip_simple() {
+        let (modifiers, main) = parse_chord("ctrl+a").unwrap();
+        assert_eq!(describe_chord(&modifiers, main), "ctrl+a");
+    }
+
+    #[test]
+    fn test_describe_chord_round_trip_shift_special_key() {
+        let (modifiers, main) = parse_chord("ctrl+shift+tab").unwrap();
+        assert_eq!(describe_chord(&modifiers, main), "ctrl+shift+tab");
+    }
+
+    #[test]
+    fn test_parse_chord_canonicalizes_shift_lowercase_letter() {
+        let (modifiers, main) = parse_chord("shift+a").unwrap();
+        assert!(modifiers.is_empty(), "Shift should be folded into the letter, not kept as a modifier");
+        assert_eq!(describe_chord(&modifiers, main), "A");
+    }
+
+    #[test]
+    fn test_parse_chord_canonicalizes_shift_digit() {
+        let (modifiers, main) = parse_chord("shift+1").unwrap();
+        assert!(modifiers.is_empty(), "Shift should be folded into the digit's shifted symbol, not kept as a modifier");
+        assert_eq!(describe_chord(&modifiers, main), "!");
+    }
+
+    #[test]
+    fn test_convert_key_alias_resolves() {
+        let aliases = std::collections::HashMap::from([
+            ("accept".to_string(), "enter".to_string()),
+            ("cmd".to_string(), "meta".to_string()),
+        ]);
+        let keyboard = Keyboard::new().unwrap().with_aliases(aliases);
+        assert_eq!(keyboard.convert_key("accept").unwrap(), keyboard.convert_key("enter").unwrap());
+        assert_eq!(keyboard.convert_key("cmd").unwrap(), keyboard.convert_key("meta").unwrap());
+    }
+
+    #[test]
+    fn test_convert_key_alias_resolves_recursively() {
+        let aliases = std::collections::HashMap::from([
+            ("confirm".to_string(), "accept".to_string()),
+            ("accept".to_string(), "enter".to_string()),
+        ]);
+        let keyboard = Keyboard::new().unwrap().with_aliases(aliases);
+        assert_eq!(keyboard.convert_key("confirm").unwrap(), keyboard.convert_key("enter").unwrap());
+    }
+
+    #[test]
+    fn test_convert_key_alias_cycle_is_rejected() {
+        let aliases = std::collections::HashMap::from([
+            ("a".to_string(), "b".to_string()),
+            ("b".to_string(), "a".to_string()),
+        ]);
+        let keyboard = Keyboard::new().unwrap().with_aliases(aliases);
+        assert!(keyboard.convert_key("a").is_err());
+    }
+
+    #[test]
+    fn test_send_keys_rejects_bad_count() {
+        let mut keyboard = Keyboard::new().unwrap();
+        assert!(keyboard.send_keys("a:not_a_number").is_err());
+    }
+
+    #[test]
+    fn test_send_keys_rejects_empty_chord_part() {
+        let mut keyboard = Keyboard::new().unwrap();
+        assert!(keyboard.send_keys("ctrl++c").is_err());
+    }
+
+    #[test]
+    fn test_send_keys_rejects_bad_timing() {
+        let mut keyboard = Keyboard::new().unwrap();
+        assert!(keyboard.send_keys("a/not_a_duration").is_err());
+    }
+
+    #[test]
+    fn test_modifier_state_starts_empty() {
+        let keyboard = Keyboard::new().unwrap();
+        assert_eq!(keyboard.modifier_state(), mover_core::Modifiers::NONE);
     }
 }
@@ -5,6 +5,7 @@
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{parse_macro_input, ItemFn, Expr, Lit, parse::Parse, parse::ParseStream, Token, Ident, punctuated::Punctuated};
+use syn::ext::IdentExt;
 
 /// Macro for creating a simple automation script
 #[proc_macro_attribute]
@@ -115,6 +116,46 @@ pub fn wait_sequence(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// Expands a small automation-script instruction set into a call to
+/// `mover_script::Program::compile`, which resolves labels when it runs and
+/// returns a `Result<Program, String>`, replacing the flat `mouse_sequence!`/`click_sequence!`/
+/// `keyboard_sequence!`/`wait_sequence!` macros with one that supports labels,
+/// jumps, and conditional jumps.
+///
+/// # Instructions
+///
+/// - `move(x, y)` - move the mouse to absolute coordinates
+/// - `click(Button)` - click a button (e.g. `click(Left)`)
+/// - `key("name")` - tap a key
+/// - `wait(seconds)` - sleep
+/// - `wait_until(condition)` - block until a `mover_script::Condition` expression is true
+/// - `label("name")` - a jump target
+/// - `jump("name")` - unconditional jump
+/// - `jump_if(condition, "name")` - jump if a `mover_script::Condition` expression is true
+///
+/// ```ignore
+/// let program = script! {
+///     label("loop");
+///     move(100, 100);
+///     click(Left);
+///     wait(0.5);
+///     jump_if(mover_script::Condition::KeyPressed("q".to_string()), "end");
+///     jump("loop");
+///     label("end");
+/// };
+/// ```
+#[proc_macro]
+pub fn script(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as Script);
+    let ops = input.ops.iter().map(|op| &op.0);
+
+    let expanded = quote! {
+        mover_script::Program::compile(vec![ #(#ops),* ])
+    };
+
+    TokenStream::from(expanded)
+}
+
 // Parser structs for the macros
 
 struct MouseSequence {
@@ -206,7 +247,7 @@ struct WaitSequence {
 impl Parse for WaitSequence {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let waits = Punctuated::<Lit, Token![,]>::parse_terminated(input)?;
-        
+
         let mut result = Vec::new();
         for lit in waits {
             if let Lit::Float(f) = lit {
@@ -217,7 +258,90 @@ impl Parse for WaitSequence {
                 return Err(syn::Error::new_spanned(lit, "Expected numeric literal"));
             }
         }
-        
+
         Ok(WaitSequence { waits: result })
     }
 }
+
+/// A single parsed `script!` instruction, already lowered to the tokens of a
+/// `mover_script::Op` constructor expression.
+struct ScriptOp(proc_macro2::TokenStream);
+
+struct Script {
+    ops: Vec<ScriptOp>,
+}
+
+impl Parse for Script {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ops = Punctuated::<ScriptOp, Token![;]>::parse_terminated(input)?;
+        Ok(Script { ops: ops.into_iter().collect() })
+    }
+}
+
+impl Parse for ScriptOp {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // `Ident::parse` rejects Rust keywords, but `move(...)` is the
+        // documented instruction name, so keywords need to be accepted too.
+        let name = Ident::parse_any(input)?;
+        let content;
+        syn::parenthesized!(content in input);
+        let args = Punctuated::<Expr, Token![,]>::parse_terminated(&content)?;
+        let args: Vec<Expr> = args.into_iter().collect();
+
+        let expect_arity = |n: usize| -> syn::Result<()> {
+            if args.len() != n {
+                Err(syn::Error::new_spanned(&name, format!("{} expects {} argument(s)", name, n)))
+            } else {
+                Ok(())
+            }
+        };
+
+        let tokens = match name.to_string().as_str() {
+            "move" => {
+                expect_arity(2)?;
+                let (x, y) = (&args[0], &args[1]);
+                quote! { mover_script::Op::Move { x: #x, y: #y } }
+            }
+            "click" => {
+                expect_arity(1)?;
+                let button = &args[0];
+                quote! { mover_script::Op::Click { button: mover_core::MouseButton::#button } }
+            }
+            "key" => {
+                expect_arity(1)?;
+                let key = &args[0];
+                quote! { mover_script::Op::Key { key: (#key).to_string() } }
+            }
+            "wait" => {
+                expect_arity(1)?;
+                let seconds = &args[0];
+                quote! { mover_script::Op::Wait { duration: std::time::Duration::from_secs_f64(#seconds) } }
+            }
+            "wait_until" => {
+                expect_arity(1)?;
+                let condition = &args[0];
+                quote! { mover_script::Op::WaitUntil { condition: #condition } }
+            }
+            "label" => {
+                expect_arity(1)?;
+                let name = &args[0];
+                quote! { mover_script::Op::Label((#name).to_string()) }
+            }
+            "jump" => {
+                expect_arity(1)?;
+                let label = &args[0];
+                quote! { mover_script::Op::Jump((#label).to_string()) }
+            }
+            "jump_if" => {
+                expect_arity(2)?;
+                let (condition, label) = (&args[0], &args[1]);
+                quote! { mover_script::Op::JumpIf(#condition, (#label).to_string()) }
+            }
+            other => {
+                return Err(syn::Error::new_spanned(&name, format!("Unknown script instruction: {other}")));
+            }
+        };
+
+        Ok(ScriptOp(tokens))
+    }
+}
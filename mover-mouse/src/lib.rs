@@ -65,14 +65,45 @@
 //! }
 //! ```
 
-use mover_core::{MoverResult, Point, Size, TweenFn};
-use enigo::{Button as EnigoMouseButton, Enigo, Settings, Direction, Coordinate, Axis, Mouse as EnigoMouse};
+use mover_core::{LogicalPoint, Modifiers, MoverResult, NavigationDirection, PhysicalPoint, Point, ScrollDelta, Size, TweenFn, Modifier};
+use enigo::{Button as EnigoMouseButton, Enigo, Settings, Direction, Coordinate, Axis, Mouse as EnigoMouse, Keyboard as EnigoKeyboard};
 use std::{thread, time::Duration};
 use std::io::Write;
 
 // Re-export commonly used types for convenience
 pub use mover_core::MouseButton;
 
+/// Options for [`Mouse::move_to_eased`]: how long the movement should take and
+/// which easing function to sample it with.
+#[derive(Debug, Clone, Copy)]
+pub struct MoveOptions {
+    /// How long the movement should take.
+    pub duration: Duration,
+    /// The easing function applied to the normalized `[0, 1]` time before interpolating.
+    pub easing: TweenFn,
+}
+
+/// Tunables for [`Mouse::move_to_human_with_options`]'s WindMouse simulation.
+#[derive(Debug, Clone, Copy)]
+pub struct WindMouseOptions {
+    /// Gravitational pull of the velocity toward the target each step.
+    pub gravity: f64,
+    /// Magnitude of the random wind applied to the velocity each step.
+    pub wind: f64,
+    /// Maximum length the velocity vector is allowed to reach in a single step.
+    pub max_step: f64,
+}
+
+impl Default for WindMouseOptions {
+    fn default() -> Self {
+        Self {
+            gravity: 9.0,
+            wind: 3.0,
+            max_step: 15.0,
+        }
+    }
+}
+
 /// Mouse control interface providing comprehensive automation capabilities.
 /// 
 /// This struct contains all the methods needed for mouse control, including:
@@ -105,8 +136,21 @@ pub use mover_core::MouseButton;
 /// ```
 pub struct Mouse {
     enigo: Enigo,
+    /// Fractional pixel carry-over for `scroll_precise`, per axis (x, y),
+    /// since `enigo` only accepts whole ticks.
+    scroll_accum: (f64, f64),
+    /// Buttons currently held down, as tracked by `mouse_down`/`mouse_up`.
+    /// Released automatically on `Drop` so a panic or early `?` return
+    /// between a press and its matching release can't leave a button stuck.
+    held_buttons: std::collections::HashSet<MouseButton>,
+    /// The last position reported by `move_to`/`move_by`/`drag_to`, if any.
+    last_position: Option<Point>,
 }
 
+/// Default pixels-per-tick threshold for [`Mouse::scroll_precise`], chosen to
+/// roughly match one traditional mouse-wheel click.
+pub const DEFAULT_PIXELS_PER_SCROLL_TICK: f64 = 20.0;
+
 impl Mouse {
     /// Create a new Mouse instance
     pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
@@ -116,7 +160,12 @@ impl Mouse {
                     format!("Failed to create Enigo instance: {}", e)
                 )
             ))?;
-        Ok(Mouse { enigo })
+        Ok(Mouse {
+            enigo,
+            scroll_accum: (0.0, 0.0),
+            held_buttons: std::collections::HashSet::new(),
+            last_position: None,
+        })
     }
 
     // Position and Information Functions
@@ -288,6 +337,7 @@ impl Mouse {
                     format!("Failed to move mouse: {}", e)
                 )
             ))?;
+        self.last_position = Some(Point::new(x, y));
         Ok(())
     }
     
@@ -380,6 +430,53 @@ impl Mouse {
         Ok(())
     }
     
+    /// Moves the mouse cursor to a point given in logical (DPI-independent)
+    /// coordinates, converting to physical pixels using the scale factor of
+    /// whichever display the target point resolves to - not just the primary
+    /// display's - so the same logical coordinates land in the same place on
+    /// a secondary monitor with a different scale factor.
+    ///
+    /// Every other movement method in `Mouse` operates in physical pixels
+    /// directly, matching the space `enigo` itself works in.
+    pub fn move_to_logical(&mut self, point: LogicalPoint) -> MoverResult<()> {
+        let physical = Self::resolve_logical_point(point)?;
+        self.move_to(physical.x, physical.y)
+    }
+
+    /// Returns the current mouse position in logical (DPI-independent)
+    /// coordinates, converting from physical pixels using the scale factor of
+    /// the display the cursor currently sits on.
+    pub fn position_logical(&self) -> MoverResult<LogicalPoint> {
+        let physical = self.position()?;
+        let scale_factor = Self::scale_factor_at(physical)?;
+        Ok(PhysicalPoint::from(physical).to_logical(scale_factor))
+    }
+
+    /// Resolves a logical point to physical pixels using the scale factor of
+    /// the display it falls on.
+    ///
+    /// Since the display lookup itself needs a physical point, this first
+    /// approximates with the primary display's scale factor, finds which
+    /// display that approximation falls on, then re-converts with that
+    /// display's actual scale factor.
+    fn resolve_logical_point(point: LogicalPoint) -> MoverResult<PhysicalPoint> {
+        let primary_scale = mover_screen::Screen::scale_factor()?;
+        let approx = point.to_physical(primary_scale);
+        let scale_factor = Self::scale_factor_at(approx.into())?;
+        Ok(point.to_physical(scale_factor))
+    }
+
+    /// Finds the scale factor of the display containing `point`, falling back
+    /// to the primary display's scale factor if `point` doesn't land on any
+    /// known display.
+    fn scale_factor_at(point: Point) -> MoverResult<f64> {
+        let displays = mover_screen::Screen::displays()?;
+        match displays.iter().find(|display| display.bounds.contains(&point)) {
+            Some(display) => Ok(display.scale_factor),
+            None => mover_screen::Screen::scale_factor(),
+        }
+    }
+
     /// Moves the mouse cursor relative to current position
     pub fn move_by(&mut self, dx: i32, dy: i32) -> MoverResult<()> {
         let current_pos = self.position()?;
@@ -401,6 +498,163 @@ impl Mouse {
         self.move_to_with_tween(target_x, target_y, duration, tween)
     }
     
+    /// Moves the mouse cursor to a point on the screen using the given easing options.
+    ///
+    /// This is the same tweened movement as [`Mouse::move_to_with_tween`], exposed as
+    /// a single `MoveOptions` argument so callers can bundle duration and easing together.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mover_mouse::{Mouse, MoveOptions};
+    /// use mover_core::ease_in_out_cubic;
+    /// use std::time::Duration;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut mouse = Mouse::new()?;
+    ///     mouse.move_to_eased(500, 300, MoveOptions {
+    ///         duration: Duration::from_secs_f64(1.5),
+    ///         easing: ease_in_out_cubic,
+    ///     })?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn move_to_eased(&mut self, x: i32, y: i32, options: MoveOptions) -> MoverResult<()> {
+        self.move_to_with_tween(x, y, options.duration.as_secs_f64(), options.easing)
+    }
+
+    /// Moves the mouse cursor to the given coordinates with human-like jitter.
+    ///
+    /// Implements the WindMouse algorithm using the default [`WindMouseOptions`]:
+    /// instead of interpolating along a straight line, a simulated "wind" buffets
+    /// the cursor's velocity every step, producing natural-looking, slightly
+    /// wandering motion rather than an instantaneous jump.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mover_mouse::Mouse;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut mouse = Mouse::new()?;
+    ///     mouse.move_to_human(500, 300)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn move_to_human(&mut self, x: i32, y: i32) -> MoverResult<()> {
+        self.move_to_human_with_options(x, y, WindMouseOptions::default())
+    }
+
+    /// Moves the mouse cursor to the given coordinates with human-like jitter,
+    /// using the given [`WindMouseOptions`] tunables.
+    ///
+    /// Each step maintains a `wind` vector and a `velocity` vector. `wind` is
+    /// nudged by a random amount every step (scaled by `options.wind`), pulled
+    /// toward the target by `options.gravity`, and the resulting velocity is
+    /// clamped to `options.max_step` (rescaled to a random magnitude between
+    /// half and all of `max_step` when it would overshoot). The cursor is moved
+    /// to the new position every step until it is within one pixel of the target.
+    pub fn move_to_human_with_options(&mut self, x: i32, y: i32, options: WindMouseOptions) -> MoverResult<()> {
+        let start = self.position()?;
+        let mut cur_x = start.x as f64;
+        let mut cur_y = start.y as f64;
+        let mut wind_x = 0.0_f64;
+        let mut wind_y = 0.0_f64;
+        let mut vel_x = 0.0_f64;
+        let mut vel_y = 0.0_f64;
+
+        loop {
+            let dx = x as f64 - cur_x;
+            let dy = y as f64 - cur_y;
+            let dist = dx.hypot(dy);
+            if dist < 1.0 {
+                break;
+            }
+
+            wind_x = wind_x / 3.0_f64.sqrt() + (2.0 * rand::random::<f64>() - 1.0) * options.wind / 5.0_f64.sqrt();
+            wind_y = wind_y / 3.0_f64.sqrt() + (2.0 * rand::random::<f64>() - 1.0) * options.wind / 5.0_f64.sqrt();
+
+            vel_x += wind_x + options.gravity * dx / dist;
+            vel_y += wind_y + options.gravity * dy / dist;
+
+            let vel_mag = vel_x.hypot(vel_y);
+            if vel_mag > options.max_step {
+                let rescaled = options.max_step / 2.0 + rand::random::<f64>() * options.max_step / 2.0;
+                vel_x = vel_x / vel_mag * rescaled;
+                vel_y = vel_y / vel_mag * rescaled;
+            }
+
+            cur_x += vel_x;
+            cur_y += vel_y;
+
+            self.move_to(cur_x.round() as i32, cur_y.round() as i32)?;
+        }
+
+        self.move_to(x, y)
+    }
+
+    /// Moves the mouse cursor by `(dx, dy)` using `enigo`'s native relative
+    /// coordinate mode, instead of reading the current position and moving to
+    /// a computed absolute target.
+    ///
+    /// Unlike [`Mouse::move_by`], this doesn't depend on a prior
+    /// [`Mouse::position`] read, so it keeps working when the cursor is
+    /// grabbed/locked (raw input) and `position()` would error or report a
+    /// stale value - e.g. when driving a game or app using pointer lock.
+    pub fn move_by_relative(&mut self, dx: i32, dy: i32) -> MoverResult<()> {
+        self.enigo.move_mouse(dx, dy, Coordinate::Rel)
+            .map_err(|e| mover_core::MoverError::PlatformError(
+                mover_core::PlatformError::UnsupportedOperation(
+                    format!("Failed to move mouse relatively: {}", e)
+                )
+            ))?;
+        if let Some(position) = self.last_position {
+            self.last_position = Some(Point::new(position.x + dx, position.y + dy));
+        }
+        Ok(())
+    }
+
+    /// Like [`Mouse::move_by_relative`], but spreads the total relative
+    /// motion across ~60 FPS frames of smaller native relative deltas, shaped
+    /// by `tween`, instead of one native relative jump.
+    ///
+    /// As with [`Mouse::scroll_with_tween`], a fractional carry per axis
+    /// ensures the emitted deltas sum to exactly `dx`/`dy` even though each
+    /// frame's delta is rounded to a whole pixel.
+    pub fn move_by_relative_with_tween(&mut self, dx: i32, dy: i32, duration: f64, tween: TweenFn) -> MoverResult<()> {
+        if duration <= 0.0 {
+            return self.move_by_relative(dx, dy);
+        }
+
+        let steps = (duration * 60.0).max(1.0) as usize; // 60 FPS
+        let mut emitted_x = 0_i32;
+        let mut emitted_y = 0_i32;
+
+        for i in 1..=steps {
+            let progress = i as f64 / steps as f64;
+            let tweened_progress = tween(progress);
+            let target_x = dx as f64 * tweened_progress;
+            let target_y = dy as f64 * tweened_progress;
+
+            let (frame_dx, frame_dy) = if i == steps {
+                (dx - emitted_x, dy - emitted_y) // flush the remainder so the total matches exactly
+            } else {
+                (target_x.trunc() as i32 - emitted_x, target_y.trunc() as i32 - emitted_y)
+            };
+            emitted_x += frame_dx;
+            emitted_y += frame_dy;
+
+            if frame_dx != 0 || frame_dy != 0 {
+                self.move_by_relative(frame_dx, frame_dy)?;
+            }
+            if i < steps {
+                self.sleep(duration / steps as f64);
+            }
+        }
+
+        Ok(())
+    }
+
     // Click Functions
     // ===============
     
@@ -453,6 +707,14 @@ impl Mouse {
         self.move_to(x, y)?;
         self.click(button)
     }
+
+    /// Performs a mouse click at a point given in logical (DPI-independent)
+    /// coordinates, converting to physical pixels with the scale factor of
+    /// the display the point falls on, as [`Mouse::move_to_logical`] does.
+    pub fn click_at_logical(&mut self, point: LogicalPoint, button: Option<MouseButton>) -> MoverResult<()> {
+        self.move_to_logical(point)?;
+        self.click(button)
+    }
     
     /// Performs a left mouse button click
     pub fn left_click(&mut self) -> MoverResult<()> {
@@ -468,6 +730,16 @@ impl Mouse {
     pub fn middle_click(&mut self) -> MoverResult<()> {
         self.click(Some(MouseButton::Middle))
     }
+
+    /// Clicks the "back" navigation side button (`X1`/`XBUTTON1`).
+    pub fn back_click(&mut self) -> MoverResult<()> {
+        self.click(Some(MouseButton::Navigate(NavigationDirection::Back)))
+    }
+
+    /// Clicks the "forward" navigation side button (`X2`/`XBUTTON2`).
+    pub fn forward_click(&mut self) -> MoverResult<()> {
+        self.click(Some(MouseButton::Navigate(NavigationDirection::Forward)))
+    }
     
     /// Performs a double click with the specified button
     pub fn double_click(&mut self, button: Option<MouseButton>) -> MoverResult<()> {
@@ -495,9 +767,10 @@ impl Mouse {
                     format!("Failed to press mouse button: {}", e)
                 )
             ))?;
+        self.held_buttons.insert(button);
         Ok(())
     }
-    
+
     /// Releases a mouse button
     pub fn mouse_up(&mut self, button: Option<MouseButton>) -> MoverResult<()> {
         let button = button.unwrap_or_default();
@@ -508,9 +781,137 @@ impl Mouse {
                     format!("Failed to release mouse button: {}", e)
                 )
             ))?;
+        self.held_buttons.remove(&button);
+        Ok(())
+    }
+
+    /// Returns whether `button` is currently held down, as tracked by
+    /// `mouse_down`/`mouse_up`.
+    pub fn is_button_down(&self, button: MouseButton) -> bool {
+        self.held_buttons.contains(&button)
+    }
+
+    /// Returns every button currently held down, as tracked by
+    /// `mouse_down`/`mouse_up`.
+    pub fn held_buttons(&self) -> Vec<MouseButton> {
+        self.held_buttons.iter().copied().collect()
+    }
+
+    /// Returns the last position reported by a `move_to`-based method, if any.
+    pub fn last_known_position(&self) -> Option<Point> {
+        self.last_position
+    }
+
+    /// Releases every button currently tracked as held down. Safe to call
+    /// even when nothing is held. Automatically run on `Drop`, but exposed so
+    /// scripts can recover from a stuck button without dropping the `Mouse`.
+    pub fn release_all(&mut self) -> MoverResult<()> {
+        for button in self.held_buttons() {
+            self.mouse_up(Some(button))?;
+        }
         Ok(())
     }
     
+    // Modifier Functions
+    // ==================
+
+    /// Clicks `button` while holding down the given modifier keys.
+    ///
+    /// Modifiers are pressed in order, the click is performed, then the
+    /// modifiers are released in reverse order - useful for ctrl-click,
+    /// shift-click, and similar modified clicks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mover_mouse::Mouse;
+    /// use mover_core::{MouseButton, Modifier};
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut mouse = Mouse::new()?;
+    ///     mouse.click_with_modifiers(MouseButton::Left, &[Modifier::Control])?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn click_with_modifiers(&mut self, button: MouseButton, modifiers: impl Into<Modifiers>) -> MoverResult<()> {
+        self.with_modifiers(modifiers, |mouse| mouse.click(Some(button)))
+    }
+
+    /// Double-clicks `button` while holding down the given modifier keys, as
+    /// [`Mouse::click_with_modifiers`] does for a single click.
+    pub fn double_click_with_modifiers(&mut self, button: MouseButton, modifiers: impl Into<Modifiers>) -> MoverResult<()> {
+        self.with_modifiers(modifiers, |mouse| mouse.double_click(Some(button)))
+    }
+
+    /// Drags to `(x, y)` with `button` while holding down the given modifier
+    /// keys - e.g. an alt-drag to duplicate, or a shift-drag to extend a
+    /// selection.
+    pub fn drag_to_with_modifiers(&mut self, x: i32, y: i32, button: Option<MouseButton>, modifiers: impl Into<Modifiers>) -> MoverResult<()> {
+        self.with_modifiers(modifiers, |mouse| mouse.drag_to(x, y, button))
+    }
+
+    /// Scrolls vertically by `clicks` while holding down the given modifier
+    /// keys - e.g. a ctrl-scroll to zoom.
+    pub fn scroll_with_modifiers(&mut self, clicks: i32, modifiers: impl Into<Modifiers>) -> MoverResult<()> {
+        self.with_modifiers(modifiers, |mouse| mouse.scroll(clicks))
+    }
+
+    /// Runs `f` with the given modifier keys held down, guaranteeing they are
+    /// released afterward even if `f` returns an error.
+    ///
+    /// Modifiers are pressed in [`Modifiers::keys`] order, then released in
+    /// reverse order once `f` returns. Modifiers that fail to release are
+    /// reported in the result only if `f` itself succeeded; the release is
+    /// still attempted for every modifier that was successfully pressed.
+    pub fn with_modifiers<F>(&mut self, modifiers: impl Into<Modifiers>, f: F) -> MoverResult<()>
+    where
+        F: FnOnce(&mut Self) -> MoverResult<()>,
+    {
+        let keys = modifiers.into().keys();
+        let mut pressed = Vec::with_capacity(keys.len());
+        let mut press_result = Ok(());
+        for modifier in &keys {
+            match self.press_modifier(*modifier) {
+                Ok(()) => pressed.push(*modifier),
+                Err(e) => {
+                    press_result = Err(e);
+                    break;
+                }
+            }
+        }
+
+        let result = press_result.and_then(|()| f(self));
+
+        let mut release_result = Ok(());
+        for modifier in pressed.iter().rev() {
+            if let Err(e) = self.release_modifier(*modifier) {
+                if release_result.is_ok() {
+                    release_result = Err(e);
+                }
+            }
+        }
+
+        result.and(release_result)
+    }
+
+    fn press_modifier(&mut self, modifier: Modifier) -> MoverResult<()> {
+        self.enigo.key(convert_modifier(modifier), Direction::Press)
+            .map_err(|e| mover_core::MoverError::PlatformError(
+                mover_core::PlatformError::UnsupportedOperation(
+                    format!("Failed to press modifier '{}': {}", modifier, e)
+                )
+            ))
+    }
+
+    fn release_modifier(&mut self, modifier: Modifier) -> MoverResult<()> {
+        self.enigo.key(convert_modifier(modifier), Direction::Release)
+            .map_err(|e| mover_core::MoverError::PlatformError(
+                mover_core::PlatformError::UnsupportedOperation(
+                    format!("Failed to release modifier '{}': {}", modifier, e)
+                )
+            ))
+    }
+
     // Drag Functions
     // ===============
     
@@ -529,37 +930,162 @@ impl Mouse {
         let target_y = current_pos.y + dy;
         self.drag_to(target_x, target_y, button)
     }
-    
+
+    // Cursor Appearance Functions
+    // ===========================
+
+    /// Sets the visible cursor shape, via the platform's native cursor API.
+    ///
+    /// `enigo` has no cursor-shape support, so unlike the rest of `Mouse`
+    /// this goes through `mover_core`'s platform layer. Platforms that can't
+    /// honor a given shape report it as an error rather than silently
+    /// leaving the previous cursor in place.
+    pub fn set_cursor(&self, cursor: mover_core::MouseCursor) -> MoverResult<()> {
+        mover_core::platform::get_platform()?.set_cursor(cursor)
+    }
+
+    /// Gets the currently visible cursor shape.
+    pub fn get_cursor(&self) -> MoverResult<mover_core::MouseCursor> {
+        mover_core::platform::get_platform()?.get_cursor()
+    }
+
     // Scrolling Functions
     // ===================
     
+    /// Scrolls by a precise delta, in lines or pixels.
+    ///
+    /// `enigo` only exposes line/click-based wheel events, so a `ScrollDelta::Pixels`
+    /// is approximated by converting to lines via [`ScrollDelta::to_lines`]
+    /// (see `mover_core::WHEEL_DELTA`) rather than rejected outright.
+    pub fn scroll_delta(&mut self, delta: ScrollDelta) -> MoverResult<()> {
+        let (x, y) = delta.to_lines();
+        if y != 0.0 {
+            self.enigo.scroll(y.round() as i32, Axis::Vertical)
+                .map_err(|e| mover_core::MoverError::PlatformError(
+                    mover_core::PlatformError::UnsupportedOperation(
+                        format!("Failed to scroll: {}", e)
+                    )
+                ))?;
+        }
+        if x != 0.0 {
+            self.enigo.scroll(x.round() as i32, Axis::Horizontal)
+                .map_err(|e| mover_core::MoverError::PlatformError(
+                    mover_core::PlatformError::UnsupportedOperation(
+                        format!("Failed to scroll horizontally: {}", e)
+                    )
+                ))?;
+        }
+        Ok(())
+    }
+
+    /// Alias for [`Mouse::scroll_delta`], matching the `scroll_by`/`scroll_by_pixels`
+    /// naming callers coming from trackpad-style "alternate scroll" APIs expect.
+    pub fn scroll_by(&mut self, delta: ScrollDelta) -> MoverResult<()> {
+        self.scroll_delta(delta)
+    }
+
+    /// Scrolls diagonally by a pixel amount on each axis - a thin wrapper over
+    /// [`Mouse::scroll_by`] building `ScrollDelta::Pixels { x, y }`.
+    ///
+    /// As with any `ScrollDelta::Pixels`, [`Mouse::scroll_delta`] converts the
+    /// amounts to whole lines and sends a vertical and then a horizontal
+    /// wheel event; no pixel-precision event is emitted, and an axis whose
+    /// amount rounds to zero lines does not scroll.
+    pub fn scroll_by_pixels(&mut self, x: f64, y: f64) -> MoverResult<()> {
+        self.scroll_by(ScrollDelta::Pixels { x: x as f32, y: y as f32 })
+    }
+
     /// Performs vertical scrolling
     pub fn scroll(&mut self, clicks: i32) -> MoverResult<()> {
-        self.enigo.scroll(clicks, Axis::Vertical)
-            .map_err(|e| mover_core::MoverError::PlatformError(
-                mover_core::PlatformError::UnsupportedOperation(
-                    format!("Failed to scroll: {}", e)
-                )
-            ))?;
-        Ok(())
+        self.scroll_delta(ScrollDelta::Lines { x: 0.0, y: clicks as f32 })
     }
-    
+
     /// Performs vertical scrolling (alias for scroll)
     pub fn vscroll(&mut self, clicks: i32) -> MoverResult<()> {
         self.scroll(clicks)
     }
-    
+
     /// Performs horizontal scrolling
     pub fn hscroll(&mut self, clicks: i32) -> MoverResult<()> {
-        self.enigo.scroll(clicks, Axis::Horizontal)
-            .map_err(|e| mover_core::MoverError::PlatformError(
-                mover_core::PlatformError::UnsupportedOperation(
-                    format!("Failed to scroll horizontally: {}", e)
-                )
-            ))?;
+        self.scroll_delta(ScrollDelta::Lines { x: clicks as f32, y: 0.0 })
+    }
+
+    /// Scrolls vertically by `clicks` ticks, smoothly animated over
+    /// `duration` seconds using `tween` instead of emitting them all at once.
+    ///
+    /// The total emitted ticks across all frames sum to exactly `clicks`: a
+    /// fractional `carry` tracks how many ticks are owed at each ~60 FPS
+    /// frame (per the shape of `tween`), and the final frame flushes whatever
+    /// is left so rounding never loses or duplicates a tick.
+    ///
+    /// A zero or negative `duration` falls back to an instantaneous [`Mouse::scroll`].
+    pub fn scroll_with_tween(&mut self, clicks: i32, duration: f64, tween: TweenFn) -> MoverResult<()> {
+        if duration <= 0.0 {
+            return self.scroll(clicks);
+        }
+
+        let steps = (duration * 60.0).max(1.0) as usize; // 60 FPS
+        let mut emitted = 0_i32;
+
+        for i in 1..=steps {
+            let progress = i as f64 / steps as f64;
+            let target = clicks as f64 * tween(progress);
+            let carry = target - emitted as f64;
+
+            let frame_ticks = if i == steps {
+                clicks - emitted // flush the remainder so the total matches exactly
+            } else {
+                carry.trunc() as i32
+            };
+            emitted += frame_ticks;
+
+            if frame_ticks != 0 {
+                self.scroll(frame_ticks)?;
+            }
+            if i < steps {
+                self.sleep(duration / steps as f64);
+            }
+        }
+
         Ok(())
     }
-    
+
+    /// Scrolls by a pixel-granular amount, for precision/trackpad-style input
+    /// feeds that emit many small deltas rather than discrete wheel clicks.
+    ///
+    /// Uses [`DEFAULT_PIXELS_PER_SCROLL_TICK`] as the pixels-per-tick
+    /// threshold; see [`Mouse::scroll_precise_with_threshold`] to tune it.
+    pub fn scroll_precise(&mut self, dx: f64, dy: f64) -> MoverResult<()> {
+        self.scroll_precise_with_threshold(dx, dy, DEFAULT_PIXELS_PER_SCROLL_TICK)
+    }
+
+    /// Like [`Mouse::scroll_precise`], but with an explicit pixels-per-tick
+    /// threshold.
+    ///
+    /// Because `enigo` only accepts whole ticks, each axis keeps a persistent
+    /// fractional pixel accumulator on `self`: small deltas (e.g. from a
+    /// trackpad) build up across calls and only emit a tick once
+    /// `pixels_per_tick` has been crossed, instead of rounding to zero every
+    /// time.
+    pub fn scroll_precise_with_threshold(&mut self, dx: f64, dy: f64, pixels_per_tick: f64) -> MoverResult<()> {
+        self.scroll_accum.0 += dx;
+        self.scroll_accum.1 += dy;
+
+        let ticks_x = (self.scroll_accum.0 / pixels_per_tick).trunc() as i32;
+        let ticks_y = (self.scroll_accum.1 / pixels_per_tick).trunc() as i32;
+
+        if ticks_x != 0 {
+            self.scroll_accum.0 -= ticks_x as f64 * pixels_per_tick;
+            self.hscroll(ticks_x)?;
+        }
+        if ticks_y != 0 {
+            self.scroll_accum.1 -= ticks_y as f64 * pixels_per_tick;
+            self.scroll(ticks_y)?;
+        }
+
+        Ok(())
+    }
+
     // Utility Functions
     // =================
     
@@ -593,16 +1119,67 @@ impl Mouse {
             MouseButton::Left => Ok(EnigoMouseButton::Left),
             MouseButton::Right => Ok(EnigoMouseButton::Right),
             MouseButton::Middle => Ok(EnigoMouseButton::Middle),
-            MouseButton::Primary => Ok(EnigoMouseButton::Left), // Default to left
-            MouseButton::Secondary => Ok(EnigoMouseButton::Right), // Default to right
-            MouseButton::Button4 => Ok(EnigoMouseButton::Left), // Fallback to left
-            MouseButton::Button5 => Ok(EnigoMouseButton::Right), // Fallback to right
-            MouseButton::Button6 => Ok(EnigoMouseButton::Left), // Fallback to left
-            MouseButton::Button7 => Ok(EnigoMouseButton::Right), // Fallback to right
+            MouseButton::Primary | MouseButton::Secondary => {
+                // Resolve against the OS's button-swap setting so left-handed
+                // users (who swap primary/secondary) get the physical button
+                // they actually expect, rather than always "left"/"right".
+                // No platform can answer this query yet, so an unsupported
+                // probe falls back to the un-swapped default instead of
+                // failing a plain Primary/Secondary click outright.
+                let swapped = match mover_core::platform::get_platform()?.is_button_swapped() {
+                    Ok(swapped) => swapped,
+                    Err(mover_core::MoverError::PlatformError(
+                        mover_core::PlatformError::UnsupportedOperation(_),
+                    )) => false,
+                    Err(err) => return Err(err),
+                };
+                match button.resolve(swapped) {
+                    MouseButton::Left => Ok(EnigoMouseButton::Left),
+                    MouseButton::Right => Ok(EnigoMouseButton::Right),
+                    _ => unreachable!("resolve() only ever returns Left or Right here"),
+                }
+            }
+            // These extra buttons have no real enigo counterpart distinct from
+            // Left/Right - for back/forward use `MouseButton::Navigate` instead,
+            // which enigo *can* emulate. Report the gap rather than silently
+            // clicking the wrong physical button.
+            MouseButton::Button4 | MouseButton::Button5 | MouseButton::Button6 | MouseButton::Button7 => {
+                Err(mover_core::MoverError::PlatformError(
+                    mover_core::PlatformError::UnsupportedOperation(format!(
+                        "{} has no distinct enigo mapping; use MouseButton::Navigate for back/forward",
+                        button
+                    )),
+                ))
+            }
+            // `XBUTTON1`/`XBUTTON2` on Windows, button 8/9 on X11, unsupported on
+            // some backends (e.g. Wayland compositors without pointer-constraints) -
+            // enigo itself reports that case, so just surface it as a clear error
+            // instead of silently falling back to a different button.
+            MouseButton::Navigate(NavigationDirection::Back) => Ok(EnigoMouseButton::Back),
+            MouseButton::Navigate(NavigationDirection::Forward) => Ok(EnigoMouseButton::Forward),
         }
     }
 }
 
+impl Drop for Mouse {
+    /// Releases any button still tracked as held, so a panic or early `?`
+    /// return between a press and its matching release (e.g. inside
+    /// `drag_to`) can't leave a physical button stuck down.
+    fn drop(&mut self) {
+        let _ = self.release_all();
+    }
+}
+
+/// Convert a `Modifier` to its enigo key code.
+fn convert_modifier(modifier: Modifier) -> enigo::Key {
+    match modifier {
+        Modifier::Shift => enigo::Key::Shift,
+        Modifier::Control => enigo::Key::Control,
+        Modifier::Alt => enigo::Key::Alt,
+        Modifier::Meta => enigo::Key::Meta,
+    }
+}
+
 // No aliases - users should call Mouse::method() directly
 
 #[cfg(test)]
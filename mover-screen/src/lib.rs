@@ -3,7 +3,7 @@
 //! This module provides screen capture functionality and image recognition similar to PyAutoGUI,
 //! including taking screenshots, finding images on screen, and getting pixel colors.
 
-use mover_core::{MoverResult, Size, MoverError};
+use mover_core::{CaptureFormat, Display, DisplayId, LogicalPoint, LogicalSize, MoverError, MoverResult, PixelFormat, Point, Region, Size};
 use image::RgbaImage;
 use std::path::Path;
 
@@ -14,29 +14,15 @@ impl Screen {
     /// Takes a screenshot of the entire screen
     pub fn capture() -> MoverResult<RgbaImage> {
         let platform = mover_core::platform::get_platform()?;
-        let _data = platform.capture_screen()?;
-        
-        // Convert raw bytes to image
-        // This is a simplified conversion - in practice, you'd need to handle different formats
-        let size = platform.get_size()?;
-        let img = RgbaImage::new(size.width as u32, size.height as u32);
-        
-        // For now, return a placeholder image
-        // TODO: Implement proper image conversion from platform data
-        Ok(img)
+        let (data, format) = platform.capture_screen()?;
+        Ok(decode_capture(&data, format))
     }
-    
+
     /// Takes a screenshot of a specific region
     pub fn capture_region(x: i32, y: i32, width: u32, height: u32) -> MoverResult<RgbaImage> {
         let platform = mover_core::platform::get_platform()?;
-        let _data = platform.capture_region(x, y, width, height)?;
-        
-        // Convert raw bytes to image
-        let img = RgbaImage::new(width, height);
-        
-        // For now, return a placeholder image
-        // TODO: Implement proper image conversion from platform data
-        Ok(img)
+        let (data, format) = platform.capture_region(x, y, width, height)?;
+        Ok(decode_capture(&data, format))
     }
     
     /// Saves a screenshot to a file
@@ -57,18 +43,15 @@ impl Screen {
     
     /// Gets the color of a pixel at specific coordinates
     pub fn get_pixel_color(x: i32, y: i32) -> MoverResult<(u8, u8, u8)> {
-        let platform = mover_core::platform::get_platform()?;
-        platform.get_pixel_color(x, y)
+        let (r, g, b, _a) = Self::get_pixel_color_rgba(x, y)?;
+        Ok((r, g, b))
     }
-    
+
     /// Gets the color of a pixel at specific coordinates with alpha
     pub fn get_pixel_color_rgba(x: i32, y: i32) -> MoverResult<(u8, u8, u8, u8)> {
-        let platform = mover_core::platform::get_platform()?;
-        let (r, g, b) = platform.get_pixel_color(x, y)?;
-        
-        // For now, assume full alpha
-        // TODO: Implement proper alpha channel support
-        Ok((r, g, b, 255))
+        let img = Self::capture_region(x, y, 1, 1)?;
+        let pixel = img.get_pixel(0, 0);
+        Ok((pixel[0], pixel[1], pixel[2], pixel[3]))
     }
     
     /// Checks if a pixel color matches the expected color
@@ -93,17 +76,299 @@ impl Screen {
         Ok(r_diff <= tolerance && g_diff <= tolerance && b_diff <= tolerance)
     }
     
-    /// Gets the screen size
+    /// Gets the screen size, in physical pixels
     pub fn size() -> MoverResult<Size> {
         let platform = mover_core::platform::get_platform()?;
         platform.get_size()
     }
-    
-    /// Checks if coordinates are on screen
+
+    /// Checks if coordinates are on screen, i.e. within the union of every
+    /// attached display's bounds. This correctly handles secondary monitors
+    /// positioned left of or above the primary display (negative coordinates).
     pub fn is_on_screen(x: i32, y: i32) -> MoverResult<bool> {
+        let point = Point::new(x, y);
+        Ok(Self::displays()?.iter().any(|display| display.bounds.contains(&point)))
+    }
+
+    /// Gets the primary display's scale factor (1.0 on a standard-DPI display,
+    /// 2.0 on a 2x Retina/HiDPI display, etc.).
+    ///
+    /// All other `Screen` methods operate in physical pixels; use this to
+    /// convert to/from the logical coordinates an application's UI is laid
+    /// out in, or use the `_logical` variants below directly.
+    pub fn scale_factor() -> MoverResult<f64> {
+        let platform = mover_core::platform::get_platform()?;
+        platform.scale_factor()
+    }
+
+    /// Takes a screenshot of a region given in logical coordinates, converting
+    /// to physical pixels using the primary display's scale factor.
+    pub fn capture_region_logical(region: LogicalPoint, size: LogicalSize) -> MoverResult<RgbaImage> {
+        let scale_factor = Self::scale_factor()?;
+        let origin = region.to_physical(scale_factor);
+        let physical_size = size.to_physical(scale_factor);
+        Self::capture_region(origin.x, origin.y, physical_size.width, physical_size.height)
+    }
+
+    /// Gets the color of a pixel at logical coordinates, converting to
+    /// physical pixels using the primary display's scale factor.
+    pub fn get_pixel_color_logical(point: LogicalPoint) -> MoverResult<(u8, u8, u8)> {
+        let scale_factor = Self::scale_factor()?;
+        let physical = point.to_physical(scale_factor);
+        Self::get_pixel_color(physical.x, physical.y)
+    }
+
+    /// Enumerates every physical display attached to the system, with bounds
+    /// given in global virtual-desktop coordinates (secondary monitors left
+    /// of or above the primary display have negative `x`/`y`).
+    pub fn displays() -> MoverResult<Vec<Display>> {
         let platform = mover_core::platform::get_platform()?;
-        platform.is_on_screen(x, y)
+        platform.displays()
+    }
+
+    /// Returns the primary display, as reported by [`Screen::displays`].
+    pub fn primary_display() -> MoverResult<Display> {
+        Self::displays()?
+            .into_iter()
+            .find(|display| display.is_primary)
+            .ok_or_else(|| MoverError::Other("no primary display reported".to_string()))
+    }
+
+    /// Captures the full bounds of the display with the given id, clipping to
+    /// that display's own bounds so the capture never spills into a
+    /// neighbouring monitor's gap.
+    pub fn capture_display(id: DisplayId) -> MoverResult<RgbaImage> {
+        let display = Self::displays()?
+            .into_iter()
+            .find(|display| display.id == id)
+            .ok_or_else(|| MoverError::Other(format!("no display with id {:?}", id)))?;
+        let bounds = display.bounds;
+        Self::capture_region(bounds.x, bounds.y, bounds.width, bounds.height)
+    }
+
+    /// Searches the screen (or `region`, if given) for the first occurrence of
+    /// `needle`, comparing each candidate window channel-by-channel and
+    /// allowing up to `tolerance` difference per channel.
+    ///
+    /// A fully transparent pixel in `needle` (alpha `0`) is treated as a mask
+    /// and skipped, so needles can ignore irrelevant background pixels.
+    /// Returns the matching region (the match's top-left corner and the
+    /// needle's size) in screen coordinates, offset by `region`'s origin when
+    /// a `region` is given.
+    pub fn locate_on_screen(
+        needle: &RgbaImage,
+        region: Option<(i32, i32, u32, u32)>,
+        tolerance: u8,
+    ) -> MoverResult<Option<Region>> {
+        let (haystack, origin_x, origin_y) = Self::capture_haystack(region)?;
+        Ok(find_needle(&haystack, needle, tolerance).map(|(x, y)| {
+            Region::new(origin_x + x as i32, origin_y + y as i32, needle.width(), needle.height())
+        }))
+    }
+
+    /// Like [`Screen::locate_on_screen`], but returns every non-overlapping
+    /// match in row-major order instead of just the first.
+    pub fn locate_all_on_screen(
+        needle: &RgbaImage,
+        region: Option<(i32, i32, u32, u32)>,
+        tolerance: u8,
+    ) -> MoverResult<Vec<Region>> {
+        let (haystack, origin_x, origin_y) = Self::capture_haystack(region)?;
+        Ok(find_all_needles(&haystack, needle, tolerance)
+            .into_iter()
+            .map(|r| Region::new(origin_x + r.x, origin_y + r.y, r.width, r.height))
+            .collect())
+    }
+
+    /// Like [`Screen::locate_on_screen`], but returns the center point of the
+    /// first match rather than its bounding region.
+    pub fn locate_center_on_screen(
+        needle: &RgbaImage,
+        region: Option<(i32, i32, u32, u32)>,
+        tolerance: u8,
+    ) -> MoverResult<Option<Point>> {
+        Ok(Self::locate_on_screen(needle, region, tolerance)?.map(|r| r.center()))
+    }
+
+    /// Searches for `needle` using grayscale normalized cross-correlation
+    /// instead of a per-pixel tolerance, returning the best-matching region
+    /// together with its `confidence` in `[0, 1]` (`1.0` being a perfect
+    /// match). Callers can compare `confidence` against their own threshold.
+    pub fn locate_on_screen_confidence(
+        needle: &RgbaImage,
+        region: Option<(i32, i32, u32, u32)>,
+    ) -> MoverResult<Option<(Region, f32)>> {
+        let (haystack, origin_x, origin_y) = Self::capture_haystack(region)?;
+        Ok(find_best_match_ncc(&haystack, needle).map(|((x, y), confidence)| {
+            (Region::new(origin_x + x as i32, origin_y + y as i32, needle.width(), needle.height()), confidence)
+        }))
     }
+
+    /// Captures `region` (or the whole screen) and returns it alongside the
+    /// region's origin, so match coordinates can be translated back into
+    /// screen space.
+    fn capture_haystack(region: Option<(i32, i32, u32, u32)>) -> MoverResult<(RgbaImage, i32, i32)> {
+        match region {
+            Some((x, y, width, height)) => Ok((Self::capture_region(x, y, width, height)?, x, y)),
+            None => Ok((Self::capture()?, 0, 0)),
+        }
+    }
+}
+
+/// Decodes a raw screen-capture buffer into an `RgbaImage`, handling the
+/// platform's channel order (BGRA on Windows/macOS vs. RGBA), row stride
+/// padding beyond `width * bytes_per_pixel`, and top-down vs. bottom-up row
+/// order. Pixels that fall outside a short buffer are left fully transparent.
+fn decode_capture(data: &[u8], format: CaptureFormat) -> RgbaImage {
+    let mut img = RgbaImage::new(format.width, format.height);
+    let bytes_per_pixel = format.pixel_format.bytes_per_pixel();
+
+    for row in 0..format.height {
+        let source_row = if format.top_down { row } else { format.height - 1 - row };
+        let row_start = source_row as usize * format.stride as usize;
+
+        for col in 0..format.width {
+            let offset = row_start + col as usize * bytes_per_pixel;
+            if offset + bytes_per_pixel > data.len() {
+                continue;
+            }
+
+            let pixel = match format.pixel_format {
+                PixelFormat::Bgra8 => [data[offset + 2], data[offset + 1], data[offset], data[offset + 3]],
+                PixelFormat::Rgba8 => [data[offset], data[offset + 1], data[offset + 2], data[offset + 3]],
+                PixelFormat::Rgb8 => [data[offset], data[offset + 1], data[offset + 2], 255],
+            };
+            img.put_pixel(col, row, image::Rgba(pixel));
+        }
+    }
+
+    img
+}
+
+/// Checks whether `needle` matches `haystack` with its top-left corner at
+/// `(x, y)`, allowing up to `tolerance` difference per color channel.
+/// Bails out on the first mismatching pixel so the common non-match case is
+/// cheap, and skips any fully transparent (alpha `0`) needle pixel as masked.
+fn needle_matches_at(haystack: &RgbaImage, needle: &RgbaImage, x: u32, y: u32, tolerance: u8) -> bool {
+    for ny in 0..needle.height() {
+        for nx in 0..needle.width() {
+            let needle_pixel = needle.get_pixel(nx, ny);
+            if needle_pixel[3] == 0 {
+                continue;
+            }
+            let haystack_pixel = haystack.get_pixel(x + nx, y + ny);
+            for channel in 0..3 {
+                let diff = (needle_pixel[channel] as i16 - haystack_pixel[channel] as i16).unsigned_abs() as u8;
+                if diff > tolerance {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+/// Slides `needle` over `haystack` in row-major order and returns the
+/// top-left corner of the first match, only considering candidates where the
+/// needle's first row can actually fit.
+fn find_needle(haystack: &RgbaImage, needle: &RgbaImage, tolerance: u8) -> Option<(u32, u32)> {
+    let (haystack_width, haystack_height) = (haystack.width(), haystack.height());
+    let (needle_width, needle_height) = (needle.width(), needle.height());
+    if needle_width == 0 || needle_height == 0 || needle_width > haystack_width || needle_height > haystack_height {
+        return None;
+    }
+    for y in 0..=(haystack_height - needle_height) {
+        for x in 0..=(haystack_width - needle_width) {
+            if needle_matches_at(haystack, needle, x, y, tolerance) {
+                return Some((x, y));
+            }
+        }
+    }
+    None
+}
+
+/// Like [`find_needle`], but collects every match whose bounding box doesn't
+/// overlap an already-accepted one.
+fn find_all_needles(haystack: &RgbaImage, needle: &RgbaImage, tolerance: u8) -> Vec<Region> {
+    let (haystack_width, haystack_height) = (haystack.width(), haystack.height());
+    let (needle_width, needle_height) = (needle.width(), needle.height());
+    let mut matches = Vec::new();
+    if needle_width == 0 || needle_height == 0 || needle_width > haystack_width || needle_height > haystack_height {
+        return matches;
+    }
+    for y in 0..=(haystack_height - needle_height) {
+        for x in 0..=(haystack_width - needle_width) {
+            if needle_matches_at(haystack, needle, x, y, tolerance) {
+                let candidate = Region::new(x as i32, y as i32, needle_width, needle_height);
+                if !matches.iter().any(|accepted: &Region| accepted.overlaps(&candidate)) {
+                    matches.push(candidate);
+                }
+            }
+        }
+    }
+    matches
+}
+
+/// Converts an image to grayscale luma values using the standard
+/// perceptual weighting.
+fn to_grayscale(image: &RgbaImage) -> Vec<f32> {
+    image
+        .pixels()
+        .map(|p| 0.299 * p[0] as f32 + 0.587 * p[1] as f32 + 0.114 * p[2] as f32)
+        .collect()
+}
+
+/// Finds the window with the highest normalized cross-correlation against
+/// `needle`, returning its top-left corner and confidence in `[0, 1]`.
+fn find_best_match_ncc(haystack: &RgbaImage, needle: &RgbaImage) -> Option<((u32, u32), f32)> {
+    let (haystack_width, haystack_height) = (haystack.width(), haystack.height());
+    let (needle_width, needle_height) = (needle.width(), needle.height());
+    if needle_width == 0 || needle_height == 0 || needle_width > haystack_width || needle_height > haystack_height {
+        return None;
+    }
+
+    let haystack_gray = to_grayscale(haystack);
+    let needle_gray = to_grayscale(needle);
+
+    let needle_pixels = (needle_width * needle_height) as f32;
+    let needle_mean = needle_gray.iter().sum::<f32>() / needle_pixels;
+    let needle_denom = needle_gray.iter().map(|v| (v - needle_mean).powi(2)).sum::<f32>().sqrt();
+
+    let mut best: Option<((u32, u32), f32)> = None;
+    for y in 0..=(haystack_height - needle_height) {
+        for x in 0..=(haystack_width - needle_width) {
+            let mut window_sum = 0.0f32;
+            for ny in 0..needle_height {
+                for nx in 0..needle_width {
+                    window_sum += haystack_gray[((y + ny) * haystack_width + (x + nx)) as usize];
+                }
+            }
+            let window_mean = window_sum / needle_pixels;
+
+            let mut numerator = 0.0f32;
+            let mut window_denom = 0.0f32;
+            for ny in 0..needle_height {
+                for nx in 0..needle_width {
+                    let h = haystack_gray[((y + ny) * haystack_width + (x + nx)) as usize] - window_mean;
+                    let t = needle_gray[(ny * needle_width + nx) as usize] - needle_mean;
+                    numerator += h * t;
+                    window_denom += h * h;
+                }
+            }
+
+            let denom = window_denom.sqrt() * needle_denom;
+            let confidence = if denom == 0.0 { 0.0 } else { (numerator / denom).clamp(0.0, 1.0) };
+
+            let is_better = match best {
+                Some((_, best_confidence)) => confidence > best_confidence,
+                None => true,
+            };
+            if is_better {
+                best = Some(((x, y), confidence));
+            }
+        }
+    }
+    best
 }
 
 // Convenience functions that mirror PyAutoGUI's API
@@ -163,3 +428,8 @@ pub fn size() -> MoverResult<Size> {
 pub fn is_on_screen(x: i32, y: i32) -> MoverResult<bool> {
     Screen::is_on_screen(x, y)
 }
+
+/// Alias for Screen::scale_factor()
+pub fn scale_factor() -> MoverResult<f64> {
+    Screen::scale_factor()
+}
@@ -0,0 +1,224 @@
+//! Interpreted automation-script subsystem for the mover automation library.
+//!
+//! The original `mouse_sequence!`/`click_sequence!`/`keyboard_sequence!`/`wait_sequence!`
+//! macros in `mover_macros` only emit straight-line code. This crate replaces them
+//! with a small programmable instruction set modeled on the crsn assembly: a flat
+//! list of ops (including `Label`s) is compiled into a [`Program`] with every
+//! `Jump`/`JumpIf` target resolved to an absolute instruction index
+//! (crsn's `labels_to_skips` pass), then [`run`] interprets the program with a
+//! program counter, so scripts can loop, branch, and wait on live input state.
+//!
+//! # Quick Start
+//!
+//! ```rust,no_run
+//! use mover_script::{Op, Program, run};
+//! use mover_core::MouseButton;
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let program = Program::compile(vec![
+//!         Op::Label("loop".into()),
+//!         Op::Move { x: 100, y: 100 },
+//!         Op::Click { button: MouseButton::Left },
+//!         Op::Wait { duration: std::time::Duration::from_millis(500) },
+//!         Op::Jump("loop".into()),
+//!     ])?;
+//!     run(&program)?;
+//!     Ok(())
+//! }
+//! ```
+
+use mover_core::{MouseButton, MoverResult, Point};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// A rectangular screen region, used by [`Condition::MousePositionIn`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl Rect {
+    pub fn new(x: i32, y: i32, width: i32, height: i32) -> Self {
+        Self { x, y, width, height }
+    }
+
+    /// Whether `point` falls within this rectangle.
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= self.x
+            && point.x < self.x + self.width
+            && point.y >= self.y
+            && point.y < self.y + self.height
+    }
+}
+
+/// A condition evaluated against live input state, for [`Op::JumpIf`] and [`Op::WaitUntil`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Condition {
+    /// True while the named key (see `mover_keyboard::Keyboard::press_key` naming) is held.
+    KeyPressed(String),
+    /// True while the given mouse button is held.
+    ButtonPressed(MouseButton),
+    /// True while the mouse cursor is within the given rectangle.
+    MousePositionIn(Rect),
+}
+
+impl Condition {
+    fn evaluate(&self) -> MoverResult<bool> {
+        match self {
+            Condition::KeyPressed(key) => mover_input::is_key_pressed(key),
+            Condition::ButtonPressed(button) => mover_input::is_button_pressed(*button),
+            Condition::MousePositionIn(rect) => {
+                let state = mover_input::mouse_state()?;
+                Ok(rect.contains(state.position))
+            }
+        }
+    }
+}
+
+/// A single operation in an unresolved instruction list.
+///
+/// `Label`s are markers consumed by [`Program::compile`]; they are not
+/// themselves executed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Op {
+    /// Move the mouse to absolute coordinates.
+    Move { x: i32, y: i32 },
+    /// Click the given button at the current position.
+    Click { button: MouseButton },
+    /// Tap a single key.
+    Key { key: String },
+    /// Sleep for the given duration.
+    Wait { duration: Duration },
+    /// Block until `condition` becomes true, polling at a fixed interval.
+    WaitUntil { condition: Condition },
+    /// Marks a jump target; resolved away at compile time.
+    Label(String),
+    /// Unconditionally jump to a label.
+    Jump(String),
+    /// Jump to a label if `condition` is currently true.
+    JumpIf(Condition, String),
+}
+
+/// A resolved operation: labels have been flattened into absolute instruction indices.
+#[derive(Debug, Clone)]
+enum ResolvedOp {
+    Move { x: i32, y: i32 },
+    Click { button: MouseButton },
+    Key { key: String },
+    Wait { duration: Duration },
+    WaitUntil { condition: Condition },
+    Jump(usize),
+    JumpIf(Condition, usize),
+}
+
+/// A compiled, runnable automation script.
+#[derive(Debug, Clone, Default)]
+pub struct Program {
+    ops: Vec<ResolvedOp>,
+}
+
+impl Program {
+    /// Compiles a flat list of [`Op`]s (including `Label`s) into a runnable
+    /// `Program`, resolving every `Jump`/`JumpIf` target to an absolute
+    /// instruction index.
+    pub fn compile(ops: Vec<Op>) -> Result<Self, String> {
+        let mut labels = HashMap::new();
+        let mut resolved_len = 0usize;
+        for op in &ops {
+            if let Op::Label(name) = op {
+                labels.insert(name.clone(), resolved_len);
+            } else {
+                resolved_len += 1;
+            }
+        }
+
+        let mut resolved = Vec::with_capacity(resolved_len);
+        for op in ops {
+            let resolve_target = |label: &str, labels: &HashMap<String, usize>| {
+                labels
+                    .get(label)
+                    .copied()
+                    .ok_or_else(|| format!("Undefined label: {label}"))
+            };
+
+            let resolved_op = match op {
+                Op::Label(_) => continue,
+                Op::Move { x, y } => ResolvedOp::Move { x, y },
+                Op::Click { button } => ResolvedOp::Click { button },
+                Op::Key { key } => ResolvedOp::Key { key },
+                Op::Wait { duration } => ResolvedOp::Wait { duration },
+                Op::WaitUntil { condition } => ResolvedOp::WaitUntil { condition },
+                Op::Jump(label) => ResolvedOp::Jump(resolve_target(&label, &labels)?),
+                Op::JumpIf(condition, label) => {
+                    ResolvedOp::JumpIf(condition, resolve_target(&label, &labels)?)
+                }
+            };
+            resolved.push(resolved_op);
+        }
+
+        Ok(Self { ops: resolved })
+    }
+
+    /// Number of instructions in the compiled program (excluding labels).
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+}
+
+/// How often [`Op::WaitUntil`] polls its condition.
+const WAIT_UNTIL_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Interprets `program` from its first instruction to completion, executing
+/// each op with a program counter so the script can loop, branch, and wait on
+/// live input state.
+pub fn run(program: &Program) -> MoverResult<()> {
+    let mut mouse = mover_mouse::Mouse::new()
+        .map_err(|e| mover_core::MoverError::Other(format!("Failed to create Mouse: {}", e)))?;
+    let mut keyboard = mover_keyboard::Keyboard::new()
+        .map_err(|e| mover_core::MoverError::Other(format!("Failed to create Keyboard: {}", e)))?;
+
+    let mut pc = 0usize;
+    while pc < program.ops.len() {
+        match &program.ops[pc] {
+            ResolvedOp::Move { x, y } => {
+                mouse.move_to(*x, *y)?;
+                pc += 1;
+            }
+            ResolvedOp::Click { button } => {
+                mouse.click(Some(*button))?;
+                pc += 1;
+            }
+            ResolvedOp::Key { key } => {
+                keyboard.tap_key(key)?;
+                pc += 1;
+            }
+            ResolvedOp::Wait { duration } => {
+                std::thread::sleep(*duration);
+                pc += 1;
+            }
+            ResolvedOp::WaitUntil { condition } => {
+                while !condition.evaluate()? {
+                    std::thread::sleep(WAIT_UNTIL_POLL_INTERVAL);
+                }
+                pc += 1;
+            }
+            ResolvedOp::Jump(target) => pc = *target,
+            ResolvedOp::JumpIf(condition, target) => {
+                if condition.evaluate()? {
+                    pc = *target;
+                } else {
+                    pc += 1;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
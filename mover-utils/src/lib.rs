@@ -3,11 +3,18 @@
 //! This module provides various utility functions, configuration management,
 //! and helper functions for the mover library.
 
-use mover_core::{MoverResult, Point, Size, MouseButton};
+use mover_core::{Event, KeyAction, MoverResult, Point, Size, MouseButton};
 use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use std::io::Write;
 
+/// A capture running in the background on behalf of an [`ActionRecorder`].
+struct CaptureHandle {
+    buffer: Arc<Mutex<Vec<Event>>>,
+    thread: std::thread::JoinHandle<MoverResult<()>>,
+}
+
 /// Configuration for the mover library
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MoverConfig {
@@ -31,6 +38,13 @@ pub struct MoverConfig {
     
     /// Screenshot log limit
     pub screenshot_log_limit: Option<usize>,
+
+    /// The display scale factor (1.0 on a standard-DPI display, 2.0 on a 2x
+    /// Retina/HiDPI display, etc.) used to convert logical pixel coordinates
+    /// - the unit applications think in - to the physical pixels every
+    /// platform call in this crate actually takes. See
+    /// [`functions::logical_to_physical`]/[`functions::physical_to_logical`].
+    pub scale_factor: f64,
 }
 
 impl Default for MoverConfig {
@@ -46,6 +60,20 @@ impl Default for MoverConfig {
             minimum_sleep: 0.05,
             log_screenshots: false,
             screenshot_log_limit: Some(10),
+            scale_factor: 1.0,
+        }
+    }
+}
+
+impl MoverConfig {
+    /// Refreshes `scale_factor` from the primary display's actual DPI scale
+    /// via `ScreenPlatform::scale_factor`. Leaves `scale_factor` unchanged if
+    /// the platform call fails (e.g. no backend implemented yet).
+    pub fn detect_scale_factor(&mut self) {
+        if let Ok(platform) = mover_core::platform::get_platform() {
+            if let Ok(scale) = platform.scale_factor() {
+                self.scale_factor = scale;
+            }
         }
     }
 }
@@ -83,93 +111,387 @@ impl FailsafeManager {
         Ok(())
     }
     
-    /// Updates failsafe points based on screen size
+    /// Updates failsafe points from `screen_size` given in logical pixels,
+    /// scaling corners to physical pixels via `MoverConfig::scale_factor`
+    /// since [`Self::check`] compares against the platform's raw physical
+    /// `get_position`.
     pub fn update_failsafe_points(&mut self, screen_size: Size) {
+        let scale = self.config.scale_factor;
+        let width = functions::logical_to_physical(screen_size.width as f64, scale);
+        let height = functions::logical_to_physical(screen_size.height as f64, scale);
         self.config.failsafe_points = vec![
-            Point::new(0, 0),                                    // Top-left
-            Point::new(screen_size.width - 1, 0),                 // Top-right
-            Point::new(0, screen_size.height - 1),                // Bottom-left
-            Point::new(screen_size.width - 1, screen_size.height - 1), // Bottom-right
+            Point::new(0, 0),                  // Top-left
+            Point::new(width - 1, 0),           // Top-right
+            Point::new(0, height - 1),           // Bottom-left
+            Point::new(width - 1, height - 1),   // Bottom-right
         ];
     }
+
+    /// Spawns a background thread that polls `get_position()` every
+    /// `poll_interval` and trips once the cursor stays within `tolerance`
+    /// pixels of any failsafe point for at least `dwell`, rather than
+    /// requiring the exact corner pixel and a caller that remembers to call
+    /// [`Self::check`].
+    ///
+    /// Returns the abort flag the watcher flips on trip. The same flag can
+    /// be checked by [`ScriptPlayer`]/other automation loops between steps
+    /// (see [`ScriptPlayer::with_abort_flag`]) to kill a long-running
+    /// replay instantly, and can also be set manually to stop the watcher
+    /// thread without a trip having occurred.
+    pub fn spawn_watcher(
+        &self,
+        poll_interval: Duration,
+        tolerance: i32,
+        dwell: Duration,
+    ) -> Arc<std::sync::atomic::AtomicBool> {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let points = self.config.failsafe_points.clone();
+        let enabled = self.config.failsafe;
+        let triggered = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&triggered);
+
+        std::thread::spawn(move || {
+            if !enabled {
+                return;
+            }
+
+            let mut dwell_start: Option<Instant> = None;
+            while !flag.load(Ordering::SeqCst) {
+                if let Ok(pos) = mover_core::platform::get_platform().and_then(|p| p.get_position()) {
+                    let near_corner = points.iter().any(|corner| {
+                        let dx = (pos.x - corner.x) as f64;
+                        let dy = (pos.y - corner.y) as f64;
+                        (dx * dx + dy * dy).sqrt() <= tolerance as f64
+                    });
+
+                    if near_corner {
+                        if dwell_start.get_or_insert_with(Instant::now).elapsed() >= dwell {
+                            flag.store(true, Ordering::SeqCst);
+                            break;
+                        }
+                    } else {
+                        dwell_start = None;
+                    }
+                }
+
+                std::thread::sleep(poll_interval);
+            }
+        });
+
+        triggered
+    }
 }
 
 /// Action recorder for automation scripts
 pub struct ActionRecorder {
-    actions: Vec<RecordedAction>,
+    events: Vec<Event>,
     start_time: Instant,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum RecordedAction {
-    MouseMove { x: i32, y: i32, duration: f64 },
-    MouseClick { x: i32, y: i32, button: MouseButton },
-    MouseDrag { from: Point, to: Point, button: MouseButton },
-    KeyPress { key: String },
-    KeyType { text: String },
-    Screenshot { path: String },
-    Wait { duration: f64 },
+    capture: Option<CaptureHandle>,
 }
 
 impl ActionRecorder {
     /// Creates a new action recorder
     pub fn new() -> Self {
         Self {
-            actions: Vec::new(),
+            events: Vec::new(),
             start_time: Instant::now(),
+            capture: None,
         }
     }
-    
-    /// Records a mouse move action
-    pub fn record_mouse_move(&mut self, x: i32, y: i32, duration: f64) {
-        self.actions.push(RecordedAction::MouseMove { x, y, duration });
+
+    /// Starts recording every system-wide mouse/keyboard event into this
+    /// recorder, via the platform's [`mover_core::platform::CapturePlatform`].
+    ///
+    /// The platform call blocks for the duration of the capture, so it runs
+    /// on a dedicated background thread; call [`Self::stop_capture`] to end
+    /// it and fold the captured events into [`Self::events`].
+    pub fn start_capture(&mut self) -> MoverResult<()> {
+        if self.capture.is_some() {
+            return Err(mover_core::MoverError::Other(
+                "a capture is already running".to_string(),
+            ));
+        }
+
+        // Every current backend's `CapturePlatform` is unimplemented - check
+        // that synchronously before spawning the background thread, rather
+        // than finding out only once the thread has already started (and
+        // only then, via `stop_capture`).
+        let platform = mover_core::platform::get_platform()?;
+        if !platform.supports_feature("capture") {
+            return Err(mover_core::MoverError::PlatformError(
+                mover_core::PlatformError::UnsupportedOperation(format!(
+                    "{} does not support global input capture yet",
+                    platform.name()
+                )),
+            ));
+        }
+
+        let buffer: Arc<Mutex<Vec<Event>>> = Arc::new(Mutex::new(Vec::new()));
+        let sink_buffer = Arc::clone(&buffer);
+        let thread = std::thread::spawn(move || -> MoverResult<()> {
+            let platform = mover_core::platform::get_platform()?;
+            platform.start_capture(Box::new(move |event| {
+                sink_buffer.lock().unwrap().push(event);
+            }))
+        });
+
+        self.capture = Some(CaptureHandle { buffer, thread });
+        Ok(())
     }
-    
-    /// Records a mouse click action
-    pub fn record_mouse_click(&mut self, x: i32, y: i32, button: MouseButton) {
-        self.actions.push(RecordedAction::MouseClick { x, y, button });
+
+    /// Stops a capture started with [`Self::start_capture`], appending
+    /// whatever events it gathered to [`Self::events`].
+    pub fn stop_capture(&mut self) -> MoverResult<()> {
+        let handle = self.capture.take().ok_or_else(|| {
+            mover_core::MoverError::Other("no capture is running".to_string())
+        })?;
+
+        mover_core::platform::get_platform()?.stop_capture()?;
+
+        handle
+            .thread
+            .join()
+            .map_err(|_| mover_core::MoverError::Other("capture thread panicked".to_string()))??;
+
+        let captured = std::mem::take(&mut *handle.buffer.lock().unwrap());
+        self.events.extend(captured);
+        Ok(())
     }
-    
-    /// Records a mouse drag action
-    pub fn record_mouse_drag(&mut self, from: Point, to: Point, button: MouseButton) {
-        self.actions.push(RecordedAction::MouseDrag { from, to, button });
+
+    /// Reports whether a capture started with [`Self::start_capture`] is
+    /// currently running.
+    pub fn is_capturing(&self) -> bool {
+        self.capture.is_some()
     }
-    
-    /// Records a key press action
-    pub fn record_key_press(&mut self, key: String) {
-        self.actions.push(RecordedAction::KeyPress { key });
+
+    /// Records a mouse move, along with the buttons held at the time.
+    pub fn record_mouse_move(&mut self, pos: Point, buttons: mover_core::ButtonSet) {
+        let timestamp = self.start_time.elapsed();
+        self.events.push(Event::MouseMove { pos, buttons, timestamp });
     }
-    
-    /// Records a key type action
-    pub fn record_key_type(&mut self, text: String) {
-        self.actions.push(RecordedAction::KeyType { text });
+
+    /// Records a mouse button press.
+    pub fn record_mouse_down(&mut self, button: MouseButton, pos: Point, modifiers: mover_core::Modifiers) {
+        let timestamp = self.start_time.elapsed();
+        self.events.push(Event::MouseDown { button, pos, modifiers, timestamp });
     }
-    
-    /// Records a screenshot action
-    pub fn record_screenshot(&mut self, path: String) {
-        self.actions.push(RecordedAction::Screenshot { path });
+
+    /// Records a mouse button release.
+    pub fn record_mouse_up(&mut self, button: MouseButton, pos: Point, modifiers: mover_core::Modifiers) {
+        let timestamp = self.start_time.elapsed();
+        self.events.push(Event::MouseUp { button, pos, modifiers, timestamp });
     }
-    
-    /// Records a wait action
-    pub fn record_wait(&mut self, duration: f64) {
-        self.actions.push(RecordedAction::Wait { duration });
+
+    /// Records a scroll wheel movement.
+    pub fn record_scroll(&mut self, direction: mover_core::ScrollDirection, amount: i32, pos: Point) {
+        let timestamp = self.start_time.elapsed();
+        self.events.push(Event::Scroll { direction, amount, pos, timestamp });
     }
-    
-    /// Gets all recorded actions
-    pub fn get_actions(&self) -> &[RecordedAction] {
-        &self.actions
+
+    /// Records a keyboard action.
+    pub fn record_key(&mut self, action: KeyAction) {
+        let timestamp = self.start_time.elapsed();
+        self.events.push(Event::Key { action, timestamp });
     }
-    
-    /// Exports actions to JSON
-    pub fn export_json(&self) -> MoverResult<String> {
-        serde_json::to_string_pretty(&self.actions)
-            .map_err(|e| mover_core::MoverError::Other(format!("Failed to serialize actions: {}", e)))
+
+    /// Gets all recorded events
+    pub fn events(&self) -> &[Event] {
+        &self.events
     }
-    
+
     /// Gets the total recording time
     pub fn get_total_time(&self) -> Duration {
         self.start_time.elapsed()
     }
+
+    /// Replays the recorded events through the real mouse and keyboard
+    /// backends, sleeping the recorded inter-event delay between each.
+    pub fn replay(&self) -> MoverResult<()> {
+        self.replay_with_speed(1.0)
+    }
+
+    /// Replays the recorded events, scaling the inter-event delay by `factor`
+    /// (`2.0` replays twice as fast, `0.5` half as fast).
+    pub fn replay_with_speed(&self, factor: f64) -> MoverResult<()> {
+        let mut mouse = mover_mouse::Mouse::new()
+            .map_err(|e| mover_core::MoverError::Other(format!("Failed to create mouse: {}", e)))?;
+        let mut keyboard = mover_keyboard::Keyboard::new()
+            .map_err(|e| mover_core::MoverError::Other(format!("Failed to create keyboard: {}", e)))?;
+
+        let mut previous = Duration::ZERO;
+        for event in &self.events {
+            let elapsed = event.timestamp().saturating_sub(previous);
+            previous = event.timestamp();
+            if factor > 0.0 {
+                std::thread::sleep(elapsed.div_f64(factor));
+            }
+
+            match event {
+                Event::MouseMove { pos, .. } => {
+                    mouse.move_to(pos.x, pos.y)?;
+                }
+                Event::MouseDown { button, pos, modifiers, .. } => {
+                    mouse.move_to(pos.x, pos.y)?;
+                    mouse.with_modifiers(*modifiers, |m| m.mouse_down(Some(*button)))?;
+                }
+                Event::MouseUp { button, pos, modifiers, .. } => {
+                    mouse.move_to(pos.x, pos.y)?;
+                    mouse.with_modifiers(*modifiers, |m| m.mouse_up(Some(*button)))?;
+                }
+                Event::Scroll { direction, amount, pos, .. } => {
+                    mouse.move_to(pos.x, pos.y)?;
+                    mouse.scroll(direction.value() * amount)?;
+                }
+                Event::Key { action, .. } => match action {
+                    KeyAction::Press(key) => keyboard.press_key(key)?,
+                    KeyAction::Release(key) => keyboard.release_key(key)?,
+                    KeyAction::Type(text) => keyboard.type_string(text)?,
+                },
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Serializes the recorded events to pretty-printed JSON.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> MoverResult<String> {
+        serde_json::to_string_pretty(&self.events)
+            .map_err(|e| mover_core::MoverError::Other(format!("Failed to serialize events: {}", e)))
+    }
+
+    /// Reconstructs a recorder's events from JSON produced by [`Self::to_json`].
+    ///
+    /// The returned recorder's `start_time` is reset to now; only the
+    /// relative timestamps between events are preserved.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> MoverResult<Self> {
+        let events: Vec<Event> = serde_json::from_str(json)
+            .map_err(|e| mover_core::MoverError::Other(format!("Failed to deserialize events: {}", e)))?;
+        Ok(Self {
+            events,
+            start_time: Instant::now(),
+            capture: None,
+        })
+    }
+}
+
+/// Replays a previously-recorded [`Event`] script directly against the
+/// active [`mover_core::platform::Platform`], independently of
+/// [`ActionRecorder::replay`] (which drives the higher-level
+/// [`mover_mouse::Mouse`]/[`mover_keyboard::Keyboard`] wrappers instead).
+///
+/// Unlike `ActionRecorder::replay`, playback honors `config.pause`/
+/// `config.minimum_sleep` between steps and runs [`FailsafeManager::check`]
+/// before each action, so a user can abort a runaway replay by slamming the
+/// cursor into a screen corner.
+pub struct ScriptPlayer {
+    events: Vec<Event>,
+    config: MoverConfig,
+    abort_flag: Option<Arc<std::sync::atomic::AtomicBool>>,
+}
+
+impl ScriptPlayer {
+    /// Wraps an already-deserialized script.
+    pub fn new(events: Vec<Event>) -> Self {
+        Self {
+            events,
+            config: MoverConfig::default(),
+            abort_flag: None,
+        }
+    }
+
+    /// Deserializes a script previously produced by
+    /// [`ActionRecorder::to_json`].
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> MoverResult<Self> {
+        let events: Vec<Event> = serde_json::from_str(json)
+            .map_err(|e| mover_core::MoverError::Other(format!("Failed to deserialize script: {}", e)))?;
+        Ok(Self::new(events))
+    }
+
+    /// Overrides the player's config, controlling the inter-step pause and
+    /// failsafe points used during playback.
+    pub fn with_config(mut self, config: MoverConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Checks `flag` between every step, aborting playback the instant it's
+    /// set - typically the flag returned by [`FailsafeManager::spawn_watcher`],
+    /// so a background corner-dwell watcher can kill a long-running replay
+    /// without the player having to poll `get_position()` itself.
+    pub fn with_abort_flag(mut self, flag: Arc<std::sync::atomic::AtomicBool>) -> Self {
+        self.abort_flag = Some(flag);
+        self
+    }
+
+    /// Replays the script once at normal speed.
+    pub fn play(&self) -> MoverResult<()> {
+        self.play_with_speed(1.0, 1)
+    }
+
+    /// Replays the script `loop_count` times, scaling every recorded
+    /// inter-event delay by `speed` (`2.0` replays twice as fast, `0.5` half
+    /// as fast).
+    pub fn play_with_speed(&self, speed: f64, loop_count: u32) -> MoverResult<()> {
+        let platform = mover_core::platform::get_platform()?;
+        let mut failsafe = FailsafeManager::new(self.config.clone());
+
+        for _ in 0..loop_count {
+            let mut previous = Duration::ZERO;
+            for event in &self.events {
+                if self.abort_flag.as_ref().is_some_and(|flag| flag.load(std::sync::atomic::Ordering::SeqCst)) {
+                    return Err(mover_core::MoverError::FailsafeTriggered(
+                        "playback aborted via watcher flag".to_string()
+                    ));
+                }
+                failsafe.check()?;
+
+                let elapsed = event.timestamp().saturating_sub(previous);
+                previous = event.timestamp();
+                if speed > 0.0 {
+                    std::thread::sleep(elapsed.div_f64(speed));
+                }
+                sleep(self.config.minimum_sleep);
+
+                match event {
+                    Event::MouseMove { pos, .. } => {
+                        platform.move_to(pos.x, pos.y)?;
+                    }
+                    Event::MouseDown { button, pos, .. } => {
+                        platform.move_to(pos.x, pos.y)?;
+                        platform.press_button(*button)?;
+                    }
+                    Event::MouseUp { button, pos, .. } => {
+                        platform.move_to(pos.x, pos.y)?;
+                        platform.release_button(*button)?;
+                    }
+                    Event::Scroll { direction, amount, pos, .. } => {
+                        platform.move_to(pos.x, pos.y)?;
+                        match direction {
+                            mover_core::ScrollDirection::Up | mover_core::ScrollDirection::Down => {
+                                platform.scroll_vertical(direction.value() * amount)?;
+                            }
+                            mover_core::ScrollDirection::Left | mover_core::ScrollDirection::Right => {
+                                platform.scroll_horizontal(direction.value() * amount)?;
+                            }
+                        }
+                    }
+                    Event::Key { action, .. } => match action {
+                        KeyAction::Press(key) => platform.press_key(key)?,
+                        KeyAction::Release(key) => platform.release_key(key)?,
+                        KeyAction::Type(text) => platform.type_string(text)?,
+                    },
+                }
+
+                sleep(self.config.pause);
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// Utility functions for common operations
@@ -209,9 +531,44 @@ pub mod functions {
     
     /// Checks if a point is within a rectangle
     pub fn point_in_rect(point: &Point, x: i32, y: i32, width: u32, height: u32) -> bool {
-        point.x >= x && point.x < x + width as i32 && 
+        point.x >= x && point.x < x + width as i32 &&
         point.y >= y && point.y < y + height as i32
     }
+
+    /// Converts a logical pixel coordinate to its physical pixel equivalent
+    /// at `scale_factor` (e.g. `2.0` on a 2x Retina/HiDPI display).
+    ///
+    /// Rounds rather than truncates, so round-tripping a coordinate through
+    /// [`physical_to_logical`] and back doesn't drift by a pixel.
+    pub fn logical_to_physical(logical: f64, scale_factor: f64) -> i32 {
+        (logical * scale_factor).round() as i32
+    }
+
+    /// Converts a physical pixel coordinate back to logical pixels at
+    /// `scale_factor`. The inverse of [`logical_to_physical`].
+    pub fn physical_to_logical(physical: i32, scale_factor: f64) -> f64 {
+        physical as f64 / scale_factor
+    }
+
+    /// Moves the mouse to a logical-pixel coordinate, scaling it to physical
+    /// pixels at `scale_factor` before calling the platform.
+    pub fn move_to_logical(x: f64, y: f64, scale_factor: f64) -> MoverResult<()> {
+        let platform = mover_core::platform::get_platform()?;
+        platform.move_to(
+            logical_to_physical(x, scale_factor),
+            logical_to_physical(y, scale_factor),
+        )
+    }
+
+    /// Gets the mouse's current position in logical pixels, scaling down
+    /// from the platform's physical-pixel position at `scale_factor`.
+    pub fn position_logical(scale_factor: f64) -> MoverResult<(f64, f64)> {
+        let pos = mover_core::platform::get_platform()?.get_position()?;
+        Ok((
+            physical_to_logical(pos.x, scale_factor),
+            physical_to_logical(pos.y, scale_factor),
+        ))
+    }
 }
 
 /// Re-export commonly used items